@@ -1,14 +1,13 @@
 use bevy::prelude::*;
 use bevy_egui::{EguiContexts, EguiPlugin, egui};
-use sim_core::{GameWorld, Player, MatchEvent, Vec2};
-use decision_plugin::{DirectLlamaEngine, LlmEngine, DecisionContext, TacticalSettings};
+use sim_core::{GameWorld, Player, MatchEvent, Trigger, Vec2};
+use decision_plugin::{DirectLlamaEngine, LlmEngine, DecisionContext, OpenZone, PlayerRole, SubstitutionRecommendation, TacticalSettings};
 use std::sync::Mutex;
 
 fn main() {
     tracing_subscriber::fmt::init();
 
     App::new()
-        .insert_resource(Time::<Fixed>::from_seconds(0.1)) // 10 Hz
         .add_plugins(DefaultPlugins.set(WindowPlugin {
             primary_window: Some(Window {
                 title: "Eleven FC - Phase 1".into(),
@@ -20,6 +19,8 @@ fn main() {
         .add_plugins(EguiPlugin)
         .insert_resource(MatchState::default())
         .insert_resource(EventLog::default())
+        .insert_resource(SubstitutionQueue::default())
+        .insert_resource(PitchControlOverlay::default())
         .insert_resource(GameWorldResource {
             world: GameWorld::new_5v5(),
         })
@@ -38,21 +39,31 @@ fn main() {
         })
         .add_systems(Startup, (setup, setup_llm_engine))
         .add_systems(
-            FixedUpdate,
+            Update,
             (
                 update_game_world,
                 update_decision_loop,
-            ),
+                render_hud,
+                render_match,
+                update_camera,
+            ).chain(),
         )
-        .add_systems(Update, (render_hud, render_match, update_camera).chain())
         .run();
 }
 
-#[derive(Resource, Default)]
+#[derive(Resource)]
 struct MatchState {
     is_running: bool,
     speed_multiplier: f32,
-    time_elapsed: f32,
+}
+
+impl Default for MatchState {
+    fn default() -> Self {
+        Self {
+            is_running: false,
+            speed_multiplier: 1.0,
+        }
+    }
 }
 
 #[derive(Resource, Default)]
@@ -60,6 +71,18 @@ struct EventLog {
     events: Vec<String>,
 }
 
+/// LLM이 추천한 교체 (감독 확정 대기)
+#[derive(Resource, Default)]
+struct SubstitutionQueue {
+    recommendations: Vec<SubstitutionRecommendation>,
+}
+
+/// 경기장 통제 오버레이 표시 여부
+#[derive(Resource, Default)]
+struct PitchControlOverlay {
+    enabled: bool,
+}
+
 #[derive(Resource)]
 struct GameWorldResource {
     world: GameWorld,
@@ -97,12 +120,13 @@ struct BallMarker;
 
 fn setup(mut commands: Commands, windows: Query<&Window>, world: Res<GameWorldResource>, mut zoom: ResMut<CameraZoom>) {
     // 경기장 크기
-    let field_width = 68.0;
-    let field_height = 105.0;
+    let pitch = &world.world.config.pitch;
+    let field_width = pitch.width;
+    let field_height = pitch.length;
     
     // 경기장 중심 좌표 (월드 좌표계)
-    let field_center_x = 34.0;
-    let field_center_y = 52.5;
+    let field_center_x = pitch.center().x;
+    let field_center_y = pitch.center().y;
     
     // 화면 크기 가져오기
     let window = windows.single();
@@ -199,6 +223,11 @@ fn create_decision_context(world: &GameWorld) -> DecisionContext {
         .iter()
         .map(|p| convert_player(p))
         .collect();
+    let bench: Vec<decision_plugin::context::Player> = world.bench
+        .iter()
+        .flatten()
+        .map(|p| convert_player(p))
+        .collect();
     
     // 경기 상태 변환
     let match_state = decision_plugin::context::MatchState {
@@ -206,17 +235,52 @@ fn create_decision_context(world: &GameWorld) -> DecisionContext {
         time_ms: world.match_state.time_ms,
         home_score: world.match_state.home_score as u32,
         away_score: world.match_state.away_score as u32,
+        pending_set_piece: world.pending_restart.as_ref().map(|restart| {
+            format!("{:?} for Team {}", restart.kind, restart.team_id)
+        }),
+        sent_off: [0, 1].map(|team_id| {
+            world.sent_off.iter().filter(|p| p.team_id == team_id).count() as u32
+        }),
+        team_size: world.config.team_size as u32,
+        pitch_size: [world.config.pitch.width, world.config.pitch.length],
     };
     
     // 현재 의도 변환
     let current_intents = world.current_intents.clone();
     
+    // 팀별 열린 구역 (경기장 통제 요약)
+    let control = &world.pitch_control;
+    let low_team = sim_core::physics::team_defending_low_end(world.match_state.ends_swapped);
+    let open_zones = [0, 1].into_iter()
+        .flat_map(|team_id| {
+            control.open_zones(&world.config.pitch, team_id, team_id == low_team)
+                .into_iter()
+                .map(move |zone| OpenZone {
+                    team_id,
+                    zone: format!("{} {}", zone.third, zone.channel),
+                    center: decision_plugin::intent::Vec2 { x: zone.center.x, y: zone.center.y },
+                    control: zone.control,
+                })
+        })
+        .collect();
+    
     DecisionContext {
         recent_events,
         players,
+        bench,
+        substitutions_remaining: [0, 1].map(|team_id| world.substitutions_remaining(team_id) as u32),
+        open_zones,
         match_state,
         current_intents,
-        tactics: TacticalSettings::default(),
+        tactics: TacticalSettings {
+            player_roles: world.players.iter()
+                .filter_map(|p| world.player_role(p.id).map(|role| PlayerRole {
+                    player_id: p.id,
+                    role_name: format!("{:?} {:?}", role.zone, role.behavior),
+                }))
+                .collect(),
+            ..TacticalSettings::default()
+        },
         current_time_ms: world.match_state.time_ms,
     }
 }
@@ -269,6 +333,7 @@ fn update_decision_loop(
     llm_resource: Res<LlmEngineResource>,
     mut timer: ResMut<DecisionTimer>,
     match_state: Res<MatchState>,
+    mut substitution_queue: ResMut<SubstitutionQueue>,
 ) {
     if !match_state.is_running {
         return;
@@ -276,8 +341,13 @@ fn update_decision_loop(
     
     let current_time_ms = world.world.match_state.time_ms;
     
+    // 세트피스가 선언되면 주기와 관계없이 즉시 재호출
+    let set_piece_awarded = world.world.pending_restart
+        .as_ref()
+        .is_some_and(|restart| restart.awarded_at_ms > timer.last_decision_ms);
+    
     // 1초 주기 체크
-    if current_time_ms < timer.last_decision_ms + timer.interval_ms {
+    if !set_piece_awarded && current_time_ms < timer.last_decision_ms + timer.interval_ms {
         return;
     }
     
@@ -298,6 +368,14 @@ fn update_decision_loop(
             Ok(action_plan) => {
                 info!("Received action plan with {} intents", action_plan.intents.len());
                 world.world.update_intents(action_plan.intents);
+                // 교체는 추천만 받고 감독이 HUD에서 확정
+                for recommendation in action_plan.substitutions {
+                    let duplicate = substitution_queue.recommendations.iter()
+                        .any(|r| r.player_out == recommendation.player_out || r.player_in == recommendation.player_in);
+                    if !duplicate {
+                        substitution_queue.recommendations.push(recommendation);
+                    }
+                }
             }
             Err(e) => {
                 warn!("Failed to generate action plan: {}", e);
@@ -309,22 +387,26 @@ fn update_decision_loop(
 fn update_game_world(
     mut world: ResMut<GameWorldResource>,
     mut match_state: ResMut<MatchState>,
-    time: Res<Time<Fixed>>,
+    time: Res<Time>,
 ) {
     if !match_state.is_running {
         return;
     }
     
-    let delta_time = time.delta().as_secs_f32() * match_state.speed_multiplier;
-    match_state.time_elapsed += delta_time;
+    // 경과한 벽시계 시간만 전달하고, 고정 100ms 스텝은 sim-core가 처리
+    world.world.advance(time.delta_seconds_f64(), match_state.speed_multiplier as f64);
     
-    world.world.tick(delta_time);
+    // 경기 종료 시 시계 정지
+    if world.world.match_state.is_finished() {
+        match_state.is_running = false;
+    }
 }
 
 fn update_camera(
     mut camera_query: Query<&mut bevy::render::camera::OrthographicProjection, With<Camera>>,
     mut transform_query: Query<&mut Transform, With<Camera>>,
     zoom: Res<CameraZoom>,
+    world: Res<GameWorldResource>,
 ) {
     // 카메라 스케일을 줌 레벨에 따라 업데이트
     if let Ok(mut projection) = camera_query.get_single_mut() {
@@ -335,8 +417,9 @@ fn update_camera(
     
     // 카메라는 경기장 중심에 고정 (월드 좌표계)
     if let Ok(mut camera_transform) = transform_query.get_single_mut() {
-        let field_center_x = 34.0;
-        let field_center_y = 52.5;
+        let center = world.world.config.pitch.center();
+        let field_center_x = center.x;
+        let field_center_y = center.y;
         
         // 경기장 중심에 카메라 고정
         camera_transform.translation = bevy::math::Vec3::new(
@@ -350,14 +433,16 @@ fn update_camera(
 fn render_match(
     mut gizmos: Gizmos,
     world: Res<GameWorldResource>,
+    overlay: Res<PitchControlOverlay>,
     _player_query: Query<&PlayerMarker>,
     _ball_query: Query<&BallMarker>,
 ) {
     // 경기장 크기
-    let field_width = 68.0;
-    let field_height = 105.0;
-    let field_center_x = 34.0;
-    let field_center_y = 52.5;
+    let pitch = &world.world.config.pitch;
+    let field_width = pitch.width;
+    let field_height = pitch.length;
+    let field_center_x = pitch.center().x;
+    let field_center_y = pitch.center().y;
     
     // 경기장 그리기 (월드 좌표계 사용, 카메라가 중심을 보고 있으므로)
     gizmos.rect_2d(
@@ -367,6 +452,24 @@ fn render_match(
         Color::rgb(0.2, 0.6, 0.2),
     );
 
+    // 경기장 통제 오버레이: 칸마다 우세한 팀 색으로, 우세할수록 크게 그림
+    if overlay.enabled {
+        let control = &world.world.pitch_control;
+        let cell = bevy::math::Vec2::new(control.cell_width, control.cell_length);
+        for (center, home) in control.cells() {
+            let dominance = (home - 0.5).abs() * 2.0;
+            if dominance < 0.1 {
+                continue;
+            }
+            let color = if home > 0.5 {
+                Color::rgba(0.2, 0.4, 1.0, 0.6)
+            } else {
+                Color::rgba(1.0, 0.2, 0.2, 0.6)
+            };
+            gizmos.rect_2d(bevy::math::Vec2::new(center.x, center.y), 0.0, cell * 0.9 * dominance, color);
+        }
+    }
+
     // 중앙선
     gizmos.line_2d(
         bevy::math::Vec2::new(0.0, field_center_y),
@@ -377,10 +480,25 @@ fn render_match(
     // 중앙원
     gizmos.circle_2d(
         bevy::math::Vec2::new(field_center_x, field_center_y),
-        9.15,
+        9.15f32.min(field_width * 0.15),
         Color::WHITE,
     );
 
+    // 골대
+    for team_id in 0..=1u8 {
+        let goal = sim_core::physics::defended_goal(pitch, team_id, world.world.match_state.ends_swapped);
+        let depth = if team_id == 0 { -2.0 } else { 2.0 };
+        let left = bevy::math::Vec2::new(goal.left_post().x, goal.left_post().y);
+        let right = bevy::math::Vec2::new(goal.right_post().x, goal.right_post().y);
+        gizmos.line_2d(left, left + bevy::math::Vec2::new(0.0, depth), Color::WHITE);
+        gizmos.line_2d(right, right + bevy::math::Vec2::new(0.0, depth), Color::WHITE);
+        gizmos.line_2d(
+            left + bevy::math::Vec2::new(0.0, depth),
+            right + bevy::math::Vec2::new(0.0, depth),
+            Color::WHITE,
+        );
+    }
+
     // 플레이어 렌더링
     for player in &world.world.players {
         let pos = bevy::math::Vec2::new(
@@ -400,6 +518,15 @@ fn render_match(
         if player.has_ball {
             gizmos.circle_2d(pos, 3.0, Color::YELLOW);
         }
+        
+        // 체력 막대 (지칠수록 짧고 붉어짐)
+        let bar_start = pos + bevy::math::Vec2::new(-2.0, -3.0);
+        let bar_color = if player.stamina < sim_core::stamina::TIRED_THRESHOLD {
+            Color::ORANGE_RED
+        } else {
+            Color::LIME_GREEN
+        };
+        gizmos.line_2d(bar_start, bar_start + bevy::math::Vec2::new(4.0 * player.stamina, 0.0), bar_color);
     }
 
     // 공 렌더링
@@ -407,7 +534,12 @@ fn render_match(
         world.world.ball.position.x,
         world.world.ball.position.y,
     );
-    gizmos.circle_2d(ball_pos, 1.5, Color::WHITE);
+    // 뜬 공은 높이에 따라 크게 그리고, 지면 위치에 그림자 표시
+    let ball_height = world.world.ball.height;
+    gizmos.circle_2d(ball_pos, 1.5 + ball_height * 0.3, Color::WHITE);
+    if ball_height > 0.0 {
+        gizmos.circle_2d(ball_pos, 0.8, Color::DARK_GRAY);
+    }
     
     // 공 소유자가 없으면 하얀색 테두리
     if world.world.ball.owner.is_none() {
@@ -418,11 +550,13 @@ fn render_match(
 fn render_hud(
     mut contexts: EguiContexts,
     mut match_state: ResMut<MatchState>,
-    world: Res<GameWorldResource>,
+    mut world: ResMut<GameWorldResource>,
     llm_resource: Res<LlmEngineResource>,
     timer: Res<DecisionTimer>,
     mut event_log: ResMut<EventLog>,
     mut zoom: ResMut<CameraZoom>,
+    mut substitution_queue: ResMut<SubstitutionQueue>,
+    mut overlay: ResMut<PitchControlOverlay>,
 ) {
     // 최신 이벤트를 로그에 추가
     if world.world.events.len() > event_log.events.len() {
@@ -434,6 +568,16 @@ fn render_hud(
                 event.player_id,
                 match &event.payload {
                     sim_core::EventPayload::Pass { target_player_id, .. } => target_player_id.clone(),
+                    sim_core::EventPayload::Goal { assist_id: Some(assist_id), .. } => format!("assist {}", assist_id),
+                    sim_core::EventPayload::Foul { on_player_id, restart } => format!("on {} ({:?})", on_player_id, restart),
+                    sim_core::EventPayload::Card { card, sent_off } => {
+                        format!("{:?}{}", card, if *sent_off { " - sent off" } else { "" })
+                    }
+                    sim_core::EventPayload::Shot { xg, .. } => format!("xG {:.2}", xg),
+                    sim_core::EventPayload::Substitution { replaced_player_id } => format!("on for {}", replaced_player_id),
+                    sim_core::EventPayload::Save { shooter_id, caught } => {
+                        format!("{} from {}", if *caught { "caught" } else { "parried" }, shooter_id)
+                    }
                     _ => "".to_string(),
                 }
             );
@@ -451,12 +595,27 @@ fn render_hud(
             
             ui.separator();
             
-            if ui.button(if match_state.is_running { "Pause" } else { "Start" }).clicked() {
+            let finished = world.world.match_state.is_finished();
+            if ui.add_enabled(!finished, egui::Button::new(if match_state.is_running { "Pause" } else { "Start" })).clicked() {
                 match_state.is_running = !match_state.is_running;
             }
             
             ui.add_space(10.0);
-            ui.label(format!("Match Time: {:.1}s", match_state.time_elapsed));
+            let sim_state = &world.world.match_state;
+            let period_seconds = sim_state.period_elapsed_ms / 1000;
+            ui.label(format!(
+                "{:?} {:02}:{:02}",
+                sim_state.period,
+                period_seconds / 60,
+                period_seconds % 60,
+            ));
+            if let Some(stoppage_ms) = sim_state.stoppage_ms {
+                ui.label(format!("Added time: +{} min", stoppage_ms / 60_000));
+            }
+            if let Some(shootout) = &sim_state.shootout {
+                ui.label(format!("Penalties: {} - {}", shootout.score(0), shootout.score(1)));
+            }
+            ui.label(format!("Match Time: {:.1}s", sim_state.time_ms as f32 / 1000.0));
             
             ui.add_space(10.0);
             ui.label("Speed:");
@@ -464,8 +623,26 @@ fn render_hud(
             
             ui.separator();
             ui.label(format!("Home: {} - {} :Away", world.world.match_state.home_score, world.world.match_state.away_score));
+            ui.label(format!("xG: {:.2} - {:.2}", world.world.team_xg(0), world.world.team_xg(1)));
             ui.label(format!("Events: {}", world.world.events.len()));
             ui.label(format!("Active Intents: {}", world.world.current_intents.len()));
+            if let Some(restart) = &world.world.pending_restart {
+                ui.label(format!("Set Piece: {:?} (Team {})", restart.kind, restart.team_id));
+            }
+            let tired: Vec<String> = world.world.players.iter()
+                .filter(|p| p.stamina < sim_core::stamina::TIRED_THRESHOLD)
+                .map(|p| format!("#{} ({:.0}%)", p.id, p.stamina * 100.0))
+                .collect();
+            if !tired.is_empty() {
+                ui.label(format!("Tired: {}", tired.join(", ")));
+            }
+            if !world.world.sent_off.is_empty() {
+                ui.label(format!(
+                    "Players: Home {} - {} Away",
+                    world.world.players_on_pitch(0),
+                    world.world.players_on_pitch(1)
+                ));
+            }
             
             ui.separator();
             ui.label("Camera Zoom:");
@@ -473,8 +650,78 @@ fn render_hud(
             ui.add(egui::Slider::new(&mut zoom.level, 25.0..=400.0)
                 .text(format!("{:.0}%", current_zoom))
                 .show_value(true));
+            ui.checkbox(&mut overlay.enabled, "Pitch Control Overlay");
+            if overlay.enabled {
+                // 오버레이와 같은 스텝의 통제로 팀별 열린 구역 표시
+                let sim = &world.world;
+                let low_team = sim_core::physics::team_defending_low_end(sim.match_state.ends_swapped);
+                for team_id in 0..=1u8 {
+                    let zones: Vec<String> = sim.pitch_control.open_zones(&sim.config.pitch, team_id, team_id == low_team)
+                        .iter()
+                        .map(|zone| format!("{} {}", zone.third, zone.channel))
+                        .collect();
+                    ui.label(format!(
+                        "{} open: {}",
+                        if team_id == 0 { "Home" } else { "Away" },
+                        if zones.is_empty() { "-".to_string() } else { zones.join(", ") }
+                    ));
+                }
+            }
         });
 
+    if world.world.match_state.is_finished() {
+        let sim_state = &world.world.match_state;
+        egui::Window::new("Full Time")
+            .anchor(egui::Align2::CENTER_TOP, [0.0, 10.0])
+            .show(contexts.ctx_mut(), |ui| {
+                ui.heading(format!("Home {} - {} Away", sim_state.home_score, sim_state.away_score));
+                if let Some(shootout) = &sim_state.shootout {
+                    ui.label(format!("Penalties: {} - {}", shootout.score(0), shootout.score(1)));
+                }
+                ui.label(match sim_state.winner() {
+                    Some(0) => "Home win",
+                    Some(_) => "Away win",
+                    None => "Draw",
+                });
+                
+                // 경기 통계
+                let stats = &world.world.stats;
+                let percent = |rate: Option<f32>| rate.map_or("-".to_string(), |rate| format!("{:.0}%", rate * 100.0));
+                let [home, away] = &stats.teams;
+                let rows = [
+                    ("Possession", format!("{:.0}%", stats.possession_share(0) * 100.0), format!("{:.0}%", stats.possession_share(1) * 100.0)),
+                    ("Shots (on target)", format!("{} ({})", home.line.shots, home.line.shots_on_target), format!("{} ({})", away.line.shots, away.line.shots_on_target)),
+                    ("xG", format!("{:.2}", home.line.xg), format!("{:.2}", away.line.xg)),
+                    ("Passes", format!("{}/{}", home.line.passes_completed, home.line.passes_attempted), format!("{}/{}", away.line.passes_completed, away.line.passes_attempted)),
+                    ("Pass completion", percent(home.line.pass_completion()), percent(away.line.pass_completion())),
+                    ("Tackles won", format!("{}/{}", home.line.tackles_won, home.line.tackles_attempted), format!("{}/{}", away.line.tackles_won, away.line.tackles_attempted)),
+                    ("Distance", format!("{:.1} km", home.line.distance_m / 1000.0), format!("{:.1} km", away.line.distance_m / 1000.0)),
+                    ("Intent success", percent(home.line.intent_success_rate()), percent(away.line.intent_success_rate())),
+                ];
+                ui.separator();
+                egui::Grid::new("full_time_stats").striped(true).show(ui, |ui| {
+                    ui.label("");
+                    ui.label("Home");
+                    ui.label("Away");
+                    ui.end_row();
+                    for (name, home, away) in rows {
+                        ui.label(name);
+                        ui.label(home);
+                        ui.label(away);
+                        ui.end_row();
+                    }
+                });
+                if ui.button("Export Stats (JSON)").clicked() {
+                    match stats.to_json().map_err(|e| e.to_string())
+                        .and_then(|json| std::fs::write("match_stats.json", json).map_err(|e| e.to_string()))
+                    {
+                        Ok(()) => info!("Match stats written to match_stats.json"),
+                        Err(e) => warn!("Failed to export match stats: {}", e),
+                    }
+                }
+            });
+    }
+
     egui::Window::new("LLM Status")
         .default_pos([10.0, 200.0])
         .show(contexts.ctx_mut(), |ui| {
@@ -518,6 +765,84 @@ fn render_hud(
             }
         });
 
+    egui::Window::new("Tactical Triggers")
+        .default_pos([620.0, 400.0])
+        .show(contexts.ctx_mut(), |ui| {
+            for team_id in 0..2 {
+                ui.horizontal(|ui| {
+                    ui.label(if team_id == 0 { "Home:" } else { "Away:" });
+                    for trigger in [Trigger::PressOnLoss, Trigger::OverlapLeft, Trigger::OverlapRight] {
+                        let triggers = &mut world.world.tactics[team_id].triggers;
+                        let mut enabled = triggers.contains(&trigger);
+                        if ui.checkbox(&mut enabled, format!("{:?}", trigger)).changed() {
+                            if enabled {
+                                triggers.push(trigger);
+                            } else {
+                                triggers.retain(|t| *t != trigger);
+                            }
+                        }
+                    }
+                });
+            }
+            ui.separator();
+            for firing in world.world.trigger_log.iter().rev().take(8) {
+                ui.label(format!(
+                    "[{:.1}s] Team {} {:?} -> {:?}",
+                    firing.t_ms as f32 / 1000.0,
+                    firing.team_id,
+                    firing.trigger,
+                    firing.player_ids
+                ));
+            }
+        });
+
+    egui::Window::new("Substitutions")
+        .default_pos([620.0, 10.0])
+        .show(contexts.ctx_mut(), |ui| {
+            ui.label(format!(
+                "Made: Home {} - {} Away (limit {})",
+                world.world.substitutions_made[0],
+                world.world.substitutions_made[1],
+                world.world.config.rules.max_substitutions
+            ));
+            for sub in &world.world.pending_substitutions {
+                ui.label(format!("Waiting for stoppage: #{} -> #{} (Team {})", sub.player_out, sub.player_in, sub.team_id));
+            }
+            ui.separator();
+            if substitution_queue.recommendations.is_empty() {
+                ui.label("No recommendations");
+            }
+            // 감독이 확정한 추천만 GameWorld에 요청
+            let mut handled = Vec::new();
+            for (index, recommendation) in substitution_queue.recommendations.iter().enumerate() {
+                ui.horizontal(|ui| {
+                    ui.label(format!(
+                        "Team {}: #{} -> #{} ({})",
+                        recommendation.team_id,
+                        recommendation.player_out,
+                        recommendation.player_in,
+                        recommendation.reason
+                    ));
+                    if ui.button("Confirm").clicked() {
+                        if let Err(e) = world.world.request_substitution(
+                            recommendation.team_id,
+                            recommendation.player_out,
+                            recommendation.player_in,
+                        ) {
+                            warn!("Substitution rejected: {}", e);
+                        }
+                        handled.push(index);
+                    }
+                    if ui.button("Dismiss").clicked() {
+                        handled.push(index);
+                    }
+                });
+            }
+            for index in handled.into_iter().rev() {
+                substitution_queue.recommendations.remove(index);
+            }
+        });
+
     egui::Window::new("Event Log")
         .default_pos([10.0, 600.0])
         .default_size([600.0, 200.0])
@@ -46,6 +46,27 @@ pub struct MatchState {
     pub time_ms: u64,
     pub home_score: u32,
     pub away_score: u32,
+    /// 대기 중인 세트피스 (예: "Corner for Team 0")
+    #[serde(default)]
+    pub pending_set_piece: Option<String>,
+    /// 퇴장으로 잃은 선수 수 (홈, 어웨이)
+    #[serde(default)]
+    pub sent_off: [u32; 2],
+    /// 팀당 출전 선수 수 (경기 형식)
+    #[serde(default = "default_team_size")]
+    pub team_size: u32,
+    /// 경기장 크기 (너비 x, 길이 y, m)
+    #[serde(default = "default_pitch_size")]
+    pub pitch_size: [f32; 2],
+}
+
+// 형식 정보가 없으면 5인제 (sim-core MatchConfig::five_a_side와 같은 인원과 경기장)
+fn default_team_size() -> u32 {
+    5
+}
+
+fn default_pitch_size() -> [f32; 2] {
+    [32.0, 50.0]
 }
 
 /// LLM 의사결정을 위한 컨텍스트
@@ -55,6 +76,15 @@ pub struct DecisionContext {
     pub recent_events: Vec<MatchEvent>,
     /// 현재 선수 상태들
     pub players: Vec<Player>,
+    /// 벤치 선수들 (교체 추천 대상)
+    #[serde(default)]
+    pub bench: Vec<Player>,
+    /// 팀별 남은 교체 횟수 (홈, 어웨이)
+    #[serde(default)]
+    pub substitutions_remaining: [u32; 2],
+    /// 팀별로 통제하는 열린 구역 (경기장 통제 요약)
+    #[serde(default)]
+    pub open_zones: Vec<OpenZone>,
     /// 경기 상태
     pub match_state: MatchState,
     /// 현재 의도들 (이전 LLM 호출 결과)
@@ -65,6 +95,18 @@ pub struct DecisionContext {
     pub current_time_ms: u64,
 }
 
+/// 팀이 통제하는 열린 구역
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OpenZone {
+    pub team_id: u8,
+    /// 팀 관점 구역 이름 (예: "attacking left")
+    pub zone: String,
+    /// 구역 중심 (경기장 좌표)
+    pub center: crate::intent::Vec2,
+    /// 팀 통제 확률 (0.0 ~ 1.0)
+    pub control: f32,
+}
+
 /// 전술 설정
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TacticalSettings {
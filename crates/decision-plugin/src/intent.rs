@@ -87,10 +87,24 @@ impl Intent {
     }
 }
 
+/// 교체 추천 (감독이 확정해야 실행됨)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SubstitutionRecommendation {
+    pub team_id: u8,
+    pub player_out: u32,
+    pub player_in: u32,
+    /// 추천 이유 (예: "tired", "booked")
+    #[serde(default)]
+    pub reason: String,
+}
+
 /// 10명 선수의 액션 플랜
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ActionPlan {
     pub intents: Vec<Intent>,
+    /// 교체 추천
+    #[serde(default)]
+    pub substitutions: Vec<SubstitutionRecommendation>,
     /// LLM 호출 시점 (ms)
     pub generated_at_ms: u64,
     /// LLM 생성 지연 (ms)
@@ -101,6 +115,7 @@ impl ActionPlan {
     pub fn new(intents: Vec<Intent>, generated_at_ms: u64, latency_ms: u64) -> Self {
         Self {
             intents,
+            substitutions: Vec::new(),
             generated_at_ms,
             latency_ms,
         }
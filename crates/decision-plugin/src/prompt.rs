@@ -9,29 +9,82 @@ impl PromptGenerator {
         let mut prompt = String::new();
         
         // 시스템 프롬프트
-        prompt.push_str("You are a tactical decision engine for a 5v5 football simulation.\n");
-        prompt.push_str("Your task is to determine actions for 10 players based on the current match situation.\n");
+        let team_size = context.match_state.team_size;
+        prompt.push_str(&format!("You are a tactical decision engine for a {}v{} football simulation.\n", team_size, team_size));
+        prompt.push_str(&format!("Your task is to determine actions for {} players based on the current match situation.\n", context.players.len()));
         prompt.push_str("For each player, decide whether to CONTINUE their current action or assign a NEW action.\n\n");
         
         // 경기 상태
         prompt.push_str("## Match State\n");
         prompt.push_str(&format!("Time: {}ms (Period: {:?})\n", context.current_time_ms, context.match_state.period));
-        prompt.push_str(&format!("Score: Home {} - {} Away\n\n", 
+        prompt.push_str(&format!("Score: Home {} - {} Away\n", 
             context.match_state.home_score, context.match_state.away_score));
+        let [pitch_width, pitch_length] = context.match_state.pitch_size;
+        prompt.push_str(&format!(
+            "Pitch: x from 0 to {:.0} (touchlines), y from 0 to {:.0} (goal lines)\n",
+            pitch_width, pitch_length
+        ));
+        if let Some(set_piece) = &context.match_state.pending_set_piece {
+            prompt.push_str(&format!("Dead ball: {} is about to be taken. Position players for the restart.\n", set_piece));
+        }
+        for (team_id, sent_off) in context.match_state.sent_off.iter().enumerate() {
+            if *sent_off > 0 {
+                let remaining = context.players.iter().filter(|p| p.team_id as usize == team_id).count();
+                prompt.push_str(&format!(
+                    "Team {} is down to {} players after {} sending-off(s). Keep that team compact; the other team should exploit the extra player.\n",
+                    team_id, remaining, sent_off
+                ));
+            }
+        }
+        prompt.push('\n');
         
         // 선수 상태
         prompt.push_str("## Players\n");
         for player in &context.players {
             let ball_status = if player.has_ball { "HAS_BALL" } else { "NO_BALL" };
             prompt.push_str(&format!(
-                "Player {} (Team {}): Position ({:.1}, {:.1}), Stamina {:.2}, Morale {:.2}, {}\n",
-                player.id, player.team_id, 
+                "Player {} (Team {}, {}): Position ({:.1}, {:.1}), Stamina {:.2}, Morale {:.2}, {}\n",
+                player.id, player.team_id, player.role,
                 player.position.x, player.position.y,
                 player.stamina, player.morale, ball_status
             ));
         }
         prompt.push_str("\n");
         
+        // 열린 구역
+        if !context.open_zones.is_empty() {
+            prompt.push_str("## Open Zones\n");
+            for team_id in 0..=1u8 {
+                let zones: Vec<String> = context.open_zones.iter()
+                    .filter(|zone| zone.team_id == team_id)
+                    .map(|zone| format!(
+                        "{} around ({:.0}, {:.0}) {:.0}%",
+                        zone.zone, zone.center.x, zone.center.y, zone.control * 100.0
+                    ))
+                    .collect();
+                if !zones.is_empty() {
+                    prompt.push_str(&format!("Team {} controls: {}\n", team_id, zones.join(", ")));
+                }
+            }
+            prompt.push('\n');
+        }
+        
+        // 벤치
+        if !context.bench.is_empty() {
+            prompt.push_str("## Bench\n");
+            prompt.push_str(&format!(
+                "Substitutions left: Home {}, Away {}\n",
+                context.substitutions_remaining[0], context.substitutions_remaining[1]
+            ));
+            for player in &context.bench {
+                prompt.push_str(&format!(
+                    "Player {} (Team {}): Stamina {:.2}, Morale {:.2}\n",
+                    player.id, player.team_id, player.stamina, player.morale
+                ));
+            }
+            prompt.push('\n');
+        }
+        
         // 최근 이벤트
         if !context.recent_events.is_empty() {
             prompt.push_str("## Recent Events\n");
@@ -88,7 +141,7 @@ impl PromptGenerator {
         
         // 출력 형식 지시
         prompt.push_str("## Your Task\n");
-        prompt.push_str("Generate a JSON response with actions for all 10 players.\n");
+        prompt.push_str(&format!("Generate a JSON response with actions for all {} players.\n", context.players.len()));
         prompt.push_str("Format:\n");
         prompt.push_str("{\n");
         prompt.push_str("  \"intents\": [\n");
@@ -101,14 +154,26 @@ impl PromptGenerator {
         prompt.push_str("        \"target_id\": <number> (if applicable)\n");
         prompt.push_str("      } (only if status is \"New\")\n");
         prompt.push_str("    }\n");
-        prompt.push_str("  ]\n");
+        prompt.push_str("  ],\n");
+        prompt.push_str("  \"substitutions\": [\n");
+        prompt.push_str("    {\"team_id\": <number>, \"player_out\": <number>, \"player_in\": <number>, \"reason\": <string>}\n");
+        prompt.push_str("  ] (optional)\n");
         prompt.push_str("}\n");
         prompt.push_str("\n");
         prompt.push_str("Important:\n");
         prompt.push_str("- Use \"Continue\" when the current action is still valid\n");
         prompt.push_str("- Use \"New\" when a new action is needed\n");
-        prompt.push_str("- Include all 10 players in the response\n");
+        prompt.push_str(&format!("- Include all {} players in the response\n", context.players.len()));
         prompt.push_str("- Actions should be tactical and context-aware\n");
+        prompt.push_str("- MarkPlayer stays goal-side of target_id, BlockSpace screens the target zone from the ball, Press closes down a carrier near the target while cutting off the most dangerous pass\n");
+        prompt.push_str("- FindPassOption moves into an open passing lane, HoldPosition keeps the formation spot, AttackSpace waits onside and runs when the carrier can play the pass\n");
+        prompt.push_str("- Goalkeepers are positioned automatically: give them \"Continue\" or \"HoldPosition\", never \"Press\" or \"AttackSpace\"\n");
+        if !context.open_zones.is_empty() {
+            prompt.push_str("- Zones are named from each team's own view (left/centre/right facing the opponent goal): send AttackSpace and FindPassOption into your team's open zones, and BlockSpace into the opponent's\n");
+        }
+        if !context.bench.is_empty() {
+            prompt.push_str("- Only recommend substitutions for tired or booked players, using bench players of the same team; the manager decides whether to make them\n");
+        }
         
         prompt
     }
@@ -154,7 +219,14 @@ impl PromptGenerator {
             ));
         }
         
-        Ok(crate::intent::ActionPlan::new(intents, generated_at_ms, latency_ms))
+        let mut plan = crate::intent::ActionPlan::new(intents, generated_at_ms, latency_ms);
+        // 교체 추천은 선택 항목: 형식이 틀린 항목은 무시
+        if let Some(substitutions) = json.get("substitutions").and_then(|v| v.as_array()) {
+            plan.substitutions = substitutions.iter()
+                .filter_map(|sub| serde_json::from_value(sub.clone()).ok())
+                .collect();
+        }
+        Ok(plan)
     }
     
     fn parse_action(action_json: &serde_json::Value) -> Option<crate::intent::Action> {
@@ -210,3 +282,200 @@ impl PromptGenerator {
     }
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::context::{MatchState, OpenZone, Persona, Player, TacticalSettings};
+    use crate::intent::Vec2;
+    
+    fn player(id: u32, team_id: u8, role: &str) -> Player {
+        Player {
+            id,
+            team_id,
+            role: role.to_string(),
+            position: Vec2 { x: 10.0, y: 20.0 },
+            stamina: 1.0,
+            morale: 0.5,
+            has_ball: false,
+            persona: Persona {
+                risk_appetite: 0.5,
+                pressing_intensity: 0.5,
+                vision_range: 20.0,
+                patience: 0.5,
+                work_rate: 0.5,
+                discipline: 0.5,
+                aggression: 0.5,
+                confidence: 0.5,
+            },
+        }
+    }
+    
+    /// 팀당 `team_size`명이 뛰는 경기 컨텍스트 (선수 0번은 골키퍼)
+    fn context(team_size: u32, pitch_size: [f32; 2]) -> DecisionContext {
+        let players = (0..2u8)
+            .flat_map(|team_id| (0..team_size).map(move |i| {
+                let role = if i == 0 { "Goalkeeper" } else { "Midfielder" };
+                player(team_id as u32 * team_size + i, team_id, role)
+            }))
+            .collect();
+        DecisionContext {
+            recent_events: Vec::new(),
+            players,
+            bench: Vec::new(),
+            substitutions_remaining: [0, 0],
+            open_zones: Vec::new(),
+            match_state: MatchState {
+                period: "H1".to_string(),
+                time_ms: 0,
+                home_score: 0,
+                away_score: 0,
+                pending_set_piece: None,
+                sent_off: [0, 0],
+                team_size,
+                pitch_size,
+            },
+            current_intents: Vec::new(),
+            tactics: TacticalSettings::default(),
+            current_time_ms: 0,
+        }
+    }
+    
+    #[test]
+    fn prompt_follows_the_match_format() {
+        let prompt = PromptGenerator::generate_prompt(&context(7, [45.0, 65.0]));
+        
+        assert!(prompt.contains("for a 7v7 football simulation"));
+        assert!(prompt.contains("Pitch: x from 0 to 45 (touchlines), y from 0 to 65 (goal lines)"));
+        assert!(prompt.contains("determine actions for 14 players"));
+        assert!(prompt.contains("actions for all 14 players"));
+        assert!(prompt.contains("Include all 14 players"));
+        assert!(!prompt.contains("10 players"));
+    }
+    
+    #[test]
+    fn missing_format_defaults_to_five_a_side() {
+        let state: MatchState = serde_json::from_str(
+            r#"{"period": "H1", "time_ms": 0, "home_score": 0, "away_score": 0}"#
+        ).unwrap();
+        assert_eq!(state.team_size, 5);
+        assert_eq!(state.pitch_size, [32.0, 50.0]);
+        
+        let mut context = context(5, [0.0, 0.0]);
+        context.match_state = state;
+        let prompt = PromptGenerator::generate_prompt(&context);
+        assert!(prompt.contains("for a 5v5 football simulation"));
+        assert!(prompt.contains("Pitch: x from 0 to 32 (touchlines), y from 0 to 50 (goal lines)"));
+    }
+    
+    #[test]
+    fn prompt_flags_a_team_playing_with_fewer_players() {
+        let mut context = context(5, [32.0, 50.0]);
+        let prompt = PromptGenerator::generate_prompt(&context);
+        assert!(!prompt.contains("is down to"));
+        
+        // 원정 팀이 한 명 퇴장당해 4명
+        context.players.retain(|p| p.id != 9);
+        context.match_state.sent_off = [0, 1];
+        let prompt = PromptGenerator::generate_prompt(&context);
+        assert!(prompt.contains("Team 1 is down to 4 players after 1 sending-off(s)"));
+        assert!(!prompt.contains("Team 0 is down to"));
+        assert!(prompt.contains("actions for all 9 players"));
+    }
+    
+    #[test]
+    fn prompt_keeps_goalkeepers_out_of_outfield_actions() {
+        let prompt = PromptGenerator::generate_prompt(&context(5, [32.0, 50.0]));
+        
+        assert!(prompt.contains("Player 0 (Team 0, Goalkeeper)"));
+        assert!(prompt.contains("Player 5 (Team 1, Goalkeeper)"));
+        assert!(prompt.contains(
+            "Goalkeepers are positioned automatically: give them \"Continue\" or \"HoldPosition\", never \"Press\" or \"AttackSpace\""
+        ));
+    }
+    
+    #[test]
+    fn prompt_summarises_open_zones_per_team() {
+        let mut context = context(5, [32.0, 50.0]);
+        let prompt = PromptGenerator::generate_prompt(&context);
+        assert!(!prompt.contains("## Open Zones"));
+        
+        let zone = |team_id: u8, zone: &str, x: f32, y: f32, control: f32| OpenZone {
+            team_id,
+            zone: zone.to_string(),
+            center: Vec2 { x, y },
+            control,
+        };
+        context.open_zones = vec![
+            zone(0, "attacking left", 5.3, 41.7, 0.82),
+            zone(1, "defensive centre", 16.0, 41.7, 0.64),
+            zone(0, "middle right", 26.7, 25.0, 0.7),
+        ];
+        let prompt = PromptGenerator::generate_prompt(&context);
+        assert!(prompt.contains(
+            "## Open Zones\nTeam 0 controls: attacking left around (5, 42) 82%, middle right around (27, 25) 70%\nTeam 1 controls: defensive centre around (16, 42) 64%\n\n"
+        ));
+        assert!(prompt.contains("send AttackSpace and FindPassOption into your team's open zones"));
+    }
+    
+    #[test]
+    fn prompt_lists_the_bench_only_when_there_is_one() {
+        let mut context = context(5, [32.0, 50.0]);
+        let prompt = PromptGenerator::generate_prompt(&context);
+        assert!(!prompt.contains("## Bench"));
+        assert!(!prompt.contains("recommend substitutions"));
+        
+        let mut tired = player(10, 1, "Midfielder");
+        tired.stamina = 0.95;
+        context.bench = vec![player(11, 0, "Midfielder"), tired];
+        context.substitutions_remaining = [3, 1];
+        let prompt = PromptGenerator::generate_prompt(&context);
+        assert!(prompt.contains("## Bench\nSubstitutions left: Home 3, Away 1\n"));
+        assert!(prompt.contains("Player 11 (Team 0): Stamina 1.00, Morale 0.50\n"));
+        assert!(prompt.contains("Player 10 (Team 1): Stamina 0.95, Morale 0.50\n"));
+        assert!(prompt.contains("\"substitutions\": ["));
+        assert!(prompt.contains("- Only recommend substitutions for tired or booked players"));
+    }
+    
+    #[test]
+    fn parse_response_reads_substitution_recommendations() {
+        let response = r#"{
+            "intents": [{"player_id": 1, "status": "Continue"}],
+            "substitutions": [
+                {"team_id": 0, "player_out": 3, "player_in": 11, "reason": "tired"},
+                {"team_id": 1, "player_out": 8, "player_in": 12}
+            ]
+        }"#;
+        let plan = PromptGenerator::parse_response(response, 1000, 50).unwrap();
+        assert_eq!(plan.intents.len(), 1);
+        let subs: Vec<(u8, u32, u32, &str)> = plan.substitutions.iter()
+            .map(|sub| (sub.team_id, sub.player_out, sub.player_in, sub.reason.as_str()))
+            .collect();
+        assert_eq!(subs, vec![(0, 3, 11, "tired"), (1, 8, 12, "")]);
+    }
+    
+    #[test]
+    fn parse_response_ignores_missing_or_malformed_substitutions() {
+        // 교체 항목이 없으면 빈 목록
+        let plan = PromptGenerator::parse_response(r#"{"intents": []}"#, 0, 0).unwrap();
+        assert!(plan.substitutions.is_empty());
+        
+        // 배열이 아니면 무시
+        let plan = PromptGenerator::parse_response(r#"{"intents": [], "substitutions": "none"}"#, 0, 0).unwrap();
+        assert!(plan.substitutions.is_empty());
+        
+        // 형식이 틀린 항목만 버리고 나머지는 유지
+        let response = r#"{
+            "intents": [],
+            "substitutions": [
+                {"team_id": 0, "player_out": 3},
+                {"team_id": "home", "player_out": 3, "player_in": 11},
+                42,
+                {"team_id": 1, "player_out": 7, "player_in": 10, "reason": "booked"}
+            ]
+        }"#;
+        let plan = PromptGenerator::parse_response(response, 0, 0).unwrap();
+        assert_eq!(plan.substitutions.len(), 1);
+        assert_eq!((plan.substitutions[0].player_out, plan.substitutions[0].player_in), (7, 10));
+        assert_eq!(plan.substitutions[0].reason, "booked");
+    }
+}
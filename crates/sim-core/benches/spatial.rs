@@ -0,0 +1,150 @@
+//! 전수 탐색과 공간 격자 근접 검색 비교 (10, 22, 44명)
+//!
+//! 충돌 회피, 공 소유권 판정, 볼 소유자의 유틸리티 계산을 측정
+
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+use sim_core::behaviors::pass_option_position;
+use sim_core::decision::{calculate_blocking_risk, shoot_utility};
+use sim_core::physics::{avoid_collision, check_ball_ownership, SpatialGrid, MIN_DISTANCE, POSSESSION_RANGE};
+use sim_core::{Ball, Goal, Pitch, Player, Vec2};
+
+const ENTITY_COUNTS: [usize; 3] = [10, 22, 44];
+
+fn random_positions(count: usize) -> Vec<Vec2> {
+    let pitch = Pitch::standard();
+    let mut rng = ChaCha8Rng::seed_from_u64(count as u64);
+    (0..count)
+        .map(|_| Vec2::new(rng.gen_range(0.0..pitch.width), rng.gen_range(0.0..pitch.length)))
+        .collect()
+}
+
+/// 임의 위치의 선수들 (홈/어웨이 번갈아 배정)
+fn random_players(count: usize) -> Vec<Player> {
+    random_positions(count).into_iter()
+        .enumerate()
+        .map(|(i, position)| Player {
+            id: i as u32,
+            team_id: (i % 2) as u8,
+            role: "Player".to_string(),
+            position,
+            velocity: Vec2::default(),
+            stamina: 1.0,
+            morale: 0.7,
+            has_ball: false,
+            persona: Default::default(),
+        })
+        .collect()
+}
+
+/// 기존 방식: 선수마다 나머지 전원의 위치 목록을 만들어 모두 검사
+fn brute_force_step(positions: &[Vec2]) -> Vec<Vec2> {
+    positions.iter()
+        .enumerate()
+        .map(|(i, position)| {
+            let other_positions: Vec<Vec2> = positions.iter()
+                .enumerate()
+                .filter(|(j, _)| *j != i)
+                .map(|(_, pos)| *pos)
+                .collect();
+            avoid_collision(*position, other_positions, MIN_DISTANCE)
+        })
+        .collect()
+}
+
+/// 격자 방식: 한 번 격자를 만든 뒤 최소 거리 안의 칸만 검사
+fn grid_step(positions: &[Vec2]) -> Vec<Vec2> {
+    let grid = SpatialGrid::from_positions(&Pitch::standard(), positions.iter().copied());
+    positions.iter()
+        .enumerate()
+        .map(|(i, position)| {
+            let nearby = grid.within(*position, MIN_DISTANCE)
+                .filter(|(j, _)| *j != i)
+                .map(|(_, pos)| pos);
+            avoid_collision(*position, nearby, MIN_DISTANCE)
+        })
+        .collect()
+}
+
+/// 선수마다 발밑의 루즈볼 소유권 판정
+fn ownership_checks(players: &[Player], grid: &SpatialGrid, rng: &mut ChaCha8Rng) -> usize {
+    players.iter()
+        .filter_map(|player| {
+            let ball = Ball::new(player.position.x + 0.5, player.position.y);
+            check_ball_ownership(&ball, players, grid, POSSESSION_RANGE, None, rng)
+        })
+        .count()
+}
+
+/// 볼 소유자 한 명의 슈팅, 패스 옵션 위치, 패스 길 차단 계산
+fn carrier_utility(carrier: &Player, players: &[Player], grid: &SpatialGrid) -> f32 {
+    let pitch = Pitch::standard();
+    let goal = Goal::new(Vec2::new(pitch.width / 2.0, pitch.length), pitch.goal_width);
+    let opponents = grid.team(1 - carrier.team_id);
+    let passing: f32 = players.iter()
+        .filter(|mate| mate.team_id == carrier.team_id && mate.id != carrier.id)
+        .map(|mate| {
+            let option = pass_option_position(&pitch, &mate.position, &carrier.position, opponents);
+            calculate_blocking_risk(&carrier.position, &option, opponents)
+        })
+        .sum();
+    shoot_utility(carrier, &goal, opponents, &carrier.persona) + passing
+}
+
+fn collision_avoidance(c: &mut Criterion) {
+    let mut group = c.benchmark_group("collision_avoidance");
+    for count in ENTITY_COUNTS {
+        let positions = random_positions(count);
+        group.bench_with_input(BenchmarkId::new("brute_force", count), &positions, |b, positions| {
+            b.iter(|| brute_force_step(black_box(positions)))
+        });
+        group.bench_with_input(BenchmarkId::new("grid", count), &positions, |b, positions| {
+            b.iter(|| grid_step(black_box(positions)))
+        });
+    }
+    group.finish();
+}
+
+/// 스텝마다 격자를 한 번 만들어 공유할 때와 판단마다 다시 만들 때 비교
+fn grid_helpers(c: &mut Criterion) {
+    let pitch = Pitch::standard();
+    let mut group = c.benchmark_group("ball_ownership");
+    for count in ENTITY_COUNTS {
+        let players = random_players(count);
+        group.bench_with_input(BenchmarkId::new("rebuilt_grid", count), &players, |b, players| {
+            let mut rng = ChaCha8Rng::seed_from_u64(1);
+            b.iter(|| ownership_checks(black_box(players), &SpatialGrid::from_players(&pitch, players), &mut rng))
+        });
+        let grid = SpatialGrid::from_players(&pitch, &players);
+        group.bench_with_input(BenchmarkId::new("shared_grid", count), &players, |b, players| {
+            let mut rng = ChaCha8Rng::seed_from_u64(1);
+            b.iter(|| ownership_checks(black_box(players), &grid, &mut rng))
+        });
+    }
+    group.finish();
+
+    let mut group = c.benchmark_group("carrier_utilities");
+    for count in ENTITY_COUNTS {
+        let players = random_players(count);
+        group.bench_with_input(BenchmarkId::new("rebuilt_grid", count), &players, |b, players| {
+            b.iter(|| {
+                players.iter()
+                    .map(|carrier| carrier_utility(carrier, players, &SpatialGrid::from_players(&pitch, black_box(players))))
+                    .sum::<f32>()
+            })
+        });
+        let grid = SpatialGrid::from_players(&pitch, &players);
+        group.bench_with_input(BenchmarkId::new("shared_grid", count), &players, |b, players| {
+            b.iter(|| {
+                players.iter()
+                    .map(|carrier| carrier_utility(carrier, black_box(players), &grid))
+                    .sum::<f32>()
+            })
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, collision_avoidance, grid_helpers);
+criterion_main!(benches);
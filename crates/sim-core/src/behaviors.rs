@@ -0,0 +1,182 @@
+use crate::config::Pitch;
+use crate::contest::distance_to_segment;
+use crate::physics::TeamGrid;
+use crate::types::{Goal, Vec2};
+
+/// 마크 대상과 유지하는 거리 (m)
+pub const MARK_DISTANCE: f32 = 2.0;
+/// 마크 위치가 골문 쪽에서 공 쪽으로 치우치는 비율
+const MARK_BALL_SIDE: f32 = 0.3;
+/// 막는 공간 앞에 서는 거리 (m)
+const BLOCK_DISTANCE: f32 = 4.0;
+/// 압박 지점에서 이 거리 안의 볼 소유자에게 달려듦 (m)
+pub const PRESS_ENGAGE_RANGE: f32 = 10.0;
+/// 압박할 때 볼 소유자와의 거리 (m)
+const PRESS_DISTANCE: f32 = 1.8;
+/// 패스 길을 찾을 때 살펴보는 반경 (m)
+const PASS_OPTION_RINGS: [f32; 3] = [0.0, 4.0, 8.0];
+/// 반경마다 살펴보는 방향 수
+const PASS_OPTION_DIRECTIONS: usize = 8;
+/// 패스를 받기 좋은 볼 소유자와의 거리 범위 (m)
+pub const PASS_OPTION_MIN_DISTANCE: f32 = 6.0;
+pub const PASS_OPTION_MAX_DISTANCE: f32 = 25.0;
+/// 이 거리 이상 열린 패스 길은 모두 같은 점수 (m)
+const OPEN_LANE_DISTANCE: f32 = 8.0;
+/// 패스 길을 찾을 때 이동 거리 1m당 감점
+const PASS_OPTION_MOVE_COST: f32 = 0.15;
+/// 침투 전 수비 라인 앞에서 기다리는 여유 (m)
+const ONSIDE_MARGIN: f32 = 1.0;
+/// 볼 소유자가 침투 지점에서 이 거리 안에 있으면 침투 시작 (m)
+const RUN_TRIGGER_DISTANCE: f32 = 30.0;
+
+/// from에서 to를 향하는 단위 벡터
+fn direction(from: &Vec2, to: &Vec2) -> Vec2 {
+    Vec2::new(to.x - from.x, to.y - from.y).normalize()
+}
+
+fn offset(position: &Vec2, direction: &Vec2, distance: f32) -> Vec2 {
+    Vec2::new(position.x + direction.x * distance, position.y + direction.y * distance)
+}
+
+/// 마크 위치: 대상의 골문 쪽에서 공 쪽으로 약간 치우쳐 일정 거리 유지
+pub fn mark_position(target: &Vec2, ball: &Vec2, own_goal: &Vec2) -> Vec2 {
+    let goal_side = direction(target, own_goal);
+    let ball_side = direction(target, ball);
+    let side = Vec2::new(
+        goal_side.x * (1.0 - MARK_BALL_SIDE) + ball_side.x * MARK_BALL_SIDE,
+        goal_side.y * (1.0 - MARK_BALL_SIDE) + ball_side.y * MARK_BALL_SIDE,
+    ).normalize();
+    offset(target, &side, MARK_DISTANCE)
+}
+
+/// 공간 막기: 공과 막을 지점 사이, 막을 지점 앞에 섬
+pub fn block_position(ball: &Vec2, zone: &Vec2) -> Vec2 {
+    let distance = BLOCK_DISTANCE.min(zone.distance(ball) / 2.0);
+    offset(zone, &direction(zone, ball), distance)
+}
+
+/// 압박 위치: 볼 소유자에게 붙되 골문 쪽과 가장 위협적인 패스 길 사이로 접근
+///
+/// 등 뒤로 패스 길을 가리는 커버 섀도우를 만들면서 골문으로 가는 길도 막음
+pub fn press_position(carrier: &Vec2, own_goal: &Vec2, shadowed: Option<&Vec2>) -> Vec2 {
+    let goal_side = direction(carrier, own_goal);
+    let side = match shadowed {
+        Some(receiver) => {
+            let lane = direction(carrier, receiver);
+            let blended = Vec2::new(goal_side.x + lane.x, goal_side.y + lane.y);
+            if blended.length() > 0.0 { blended.normalize() } else { goal_side }
+        }
+        None => goal_side,
+    };
+    offset(carrier, &side, PRESS_DISTANCE)
+}
+
+/// 패스 옵션: 주변 후보 지점 중 상대가 패스 길에서 가장 멀리 떨어진 곳
+///
+/// 이동 거리가 길수록 감점하고, 볼 소유자와 너무 가깝거나 먼 지점은 제외 (`opponents`는 상대 팀 선수 격자)
+pub fn pass_option_position(pitch: &Pitch, player: &Vec2, carrier: &Vec2, opponents: TeamGrid<'_>) -> Vec2 {
+    let lane_score = |candidate: &Vec2| {
+        let openness = opponents.near_segment(*carrier, *candidate, OPEN_LANE_DISTANCE)
+            .map(|(_, opponent)| distance_to_segment(&opponent, carrier, candidate))
+            .fold(OPEN_LANE_DISTANCE, f32::min);
+        openness - player.distance(candidate) * PASS_OPTION_MOVE_COST
+    };
+    let candidates = PASS_OPTION_RINGS.iter().flat_map(|&radius| {
+        let directions = if radius > 0.0 { PASS_OPTION_DIRECTIONS } else { 1 };
+        (0..directions).map(move |i| {
+            let angle = i as f32 / directions as f32 * std::f32::consts::TAU;
+            Vec2::new(
+                (player.x + angle.cos() * radius).clamp(1.0, pitch.width - 1.0),
+                (player.y + angle.sin() * radius).clamp(1.0, pitch.length - 1.0),
+            )
+        })
+    });
+    candidates
+        .filter(|candidate| {
+            (PASS_OPTION_MIN_DISTANCE..=PASS_OPTION_MAX_DISTANCE).contains(&candidate.distance(carrier))
+        })
+        .map(|candidate| (lane_score(&candidate), candidate))
+        .max_by(|(a, _), (b, _)| a.total_cmp(b))
+        .map(|(_, candidate)| candidate)
+        .unwrap_or(*player)
+}
+
+/// 공간 침투: 목표 지점과 스프린트 여부
+///
+/// 볼 소유자가 가까이 와서 패스할 수 있을 때 스프린트로 침투하고, 그 전에는 수비 라인
+/// (골문에서 `line_depth` 거리, 오프사이드가 없으면 None) 바로 앞에서 온사이드로 기다림
+pub fn attacking_run(target: &Vec2, attacked_goal: &Goal, line_depth: Option<f32>, carrier: Option<&Vec2>) -> (Vec2, bool) {
+    if carrier.is_some_and(|carrier| carrier.distance(target) < RUN_TRIGGER_DISTANCE) {
+        return (*target, true);
+    }
+    let Some(line_depth) = line_depth else {
+        return (*target, false);
+    };
+    let wait_depth = line_depth + ONSIDE_MARGIN;
+    if (target.y - attacked_goal.center.y).abs() >= wait_depth {
+        return (*target, false);
+    }
+    (Vec2::new(target.x, attacked_goal.center.y + attacked_goal.inward() * wait_depth), false)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::physics::SpatialGrid;
+    use crate::types::Player;
+
+    #[test]
+    fn defensive_behaviours_stand_goal_side() {
+        let own_goal = Vec2::new(34.0, 0.0);
+        let attacker = Vec2::new(34.0, 30.0);
+        let ball = Vec2::new(50.0, 40.0);
+
+        let mark = mark_position(&attacker, &ball, &own_goal);
+        assert!((mark.distance(&attacker) - MARK_DISTANCE).abs() < 1e-3);
+        assert!(mark.y < attacker.y);
+        assert!(mark.x > attacker.x, "marker leans towards the ball side");
+
+        let zone = Vec2::new(20.0, 20.0);
+        let block = block_position(&ball, &zone);
+        assert!(block.distance(&ball) < zone.distance(&ball));
+        assert!(distance_to_segment(&block, &ball, &zone) < 1e-3);
+
+        // 커버 섀도우: 패스 길 쪽으로 치우쳐 소유자와 리시버 사이를 가림
+        let receiver = Vec2::new(20.0, 30.0);
+        let press = press_position(&ball, &own_goal, Some(&receiver));
+        let plain = press_position(&ball, &own_goal, None);
+        assert!(distance_to_segment(&press, &ball, &receiver) < distance_to_segment(&plain, &ball, &receiver));
+        assert!(press.y < ball.y);
+    }
+
+    #[test]
+    fn attacking_movement_uses_open_lanes_and_waits_onside() {
+        let pitch = Pitch::standard();
+        let carrier = Vec2::new(34.0, 40.0);
+        let player = Vec2::new(34.0, 55.0);
+        // 리시버 바로 앞을 막는 수비수 → 옆으로 비켜서 패스 길을 엶
+        let defender = Player {
+            id: 7,
+            team_id: 1,
+            role: "Player".to_string(),
+            position: Vec2::new(34.0, 50.0),
+            velocity: Vec2::default(),
+            stamina: 1.0,
+            morale: 0.7,
+            has_ball: false,
+            persona: Default::default(),
+        };
+        let grid = SpatialGrid::from_players(&pitch, std::slice::from_ref(&defender));
+        let option = pass_option_position(&pitch, &player, &carrier, grid.team(1));
+        assert!(distance_to_segment(&defender.position, &carrier, &option) > 2.0);
+
+        let goal = Goal::new(Vec2::new(34.0, 105.0), 7.32);
+        let target = Vec2::new(30.0, 95.0);
+        let (wait, sprint) = attacking_run(&target, &goal, Some(20.0), Some(&Vec2::new(30.0, 40.0)));
+        assert!(!sprint);
+        assert!((wait.y - 84.0).abs() < 1e-3);
+        let (run, sprint) = attacking_run(&target, &goal, Some(20.0), Some(&Vec2::new(30.0, 70.0)));
+        assert!(sprint);
+        assert_eq!(run.y, target.y);
+    }
+}
@@ -0,0 +1,144 @@
+use serde::{Deserialize, Serialize};
+use crate::types::{MatchRules, Vec2};
+
+/// 경기장 규격 (m)
+///
+/// x축은 터치라인 사이(0 ~ width), y축은 골라인 사이(0 ~ length)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Pitch {
+    /// 터치라인 사이 너비
+    pub width: f32,
+    /// 골라인 사이 길이
+    pub length: f32,
+    /// 골포스트 사이 너비
+    pub goal_width: f32,
+    /// 크로스바 높이
+    pub goal_height: f32,
+    /// 골 에어리어 깊이 (골킥 위치)
+    pub goal_area_depth: f32,
+    /// 페널티 에어리어 깊이
+    pub penalty_area_depth: f32,
+    /// 페널티 에어리어 너비
+    pub penalty_area_width: f32,
+    /// 골라인에서 페널티 마크까지 거리
+    pub penalty_spot_distance: f32,
+}
+
+impl Pitch {
+    /// 11인제 정규 경기장 (105 x 68)
+    pub fn standard() -> Self {
+        Self {
+            width: 68.0,
+            length: 105.0,
+            goal_width: 7.32,
+            goal_height: 2.44,
+            goal_area_depth: 5.5,
+            penalty_area_depth: 16.5,
+            penalty_area_width: 40.32,
+            penalty_spot_distance: 11.0,
+        }
+    }
+
+    /// 7인제 경기장 (65 x 45, 5 x 2 골대)
+    pub fn seven_a_side() -> Self {
+        Self {
+            width: 45.0,
+            length: 65.0,
+            goal_width: 5.0,
+            goal_height: 2.0,
+            goal_area_depth: 4.0,
+            penalty_area_depth: 12.0,
+            penalty_area_width: 28.0,
+            penalty_spot_distance: 9.0,
+        }
+    }
+
+    /// 5인제 경기장 (50 x 32, 3.66 x 2 골대)
+    pub fn five_a_side() -> Self {
+        Self {
+            width: 32.0,
+            length: 50.0,
+            goal_width: 3.66,
+            goal_height: 2.0,
+            goal_area_depth: 3.0,
+            penalty_area_depth: 9.0,
+            penalty_area_width: 20.0,
+            penalty_spot_distance: 7.0,
+        }
+    }
+
+    /// 센터 마크
+    pub fn center(&self) -> Vec2 {
+        Vec2::new(self.width / 2.0, self.length / 2.0)
+    }
+}
+
+impl Default for Pitch {
+    fn default() -> Self {
+        Self::standard()
+    }
+}
+
+/// 경기 형식 (팀 인원, 벤치, 경기장, 규칙)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MatchConfig {
+    /// 팀당 출전 선수 수 (골키퍼 포함)
+    pub team_size: usize,
+    /// 팀당 벤치 선수 수
+    #[serde(default)]
+    pub bench_size: usize,
+    pub pitch: Pitch,
+    pub rules: MatchRules,
+}
+
+impl MatchConfig {
+    /// 5인제: 작은 경기장, 오프사이드 없음, 벤치 5명, 교체 5명
+    pub fn five_a_side() -> Self {
+        Self {
+            team_size: 5,
+            bench_size: 5,
+            pitch: Pitch::five_a_side(),
+            rules: MatchRules {
+                offside: false,
+                max_substitutions: 5,
+                ..MatchRules::default()
+            },
+        }
+    }
+
+    /// 7인제: 중간 경기장, 오프사이드 없음, 벤치 5명, 교체 5명
+    pub fn seven_a_side() -> Self {
+        Self {
+            team_size: 7,
+            bench_size: 5,
+            pitch: Pitch::seven_a_side(),
+            rules: MatchRules {
+                half_length_ms: 25 * 60 * 1000,
+                offside: false,
+                max_substitutions: 5,
+                ..MatchRules::default()
+            },
+        }
+    }
+
+    /// 11인제: 정규 경기장, 45분 하프, 오프사이드 적용, 벤치 7명, 교체 5명
+    pub fn eleven_a_side() -> Self {
+        Self {
+            team_size: 11,
+            bench_size: 7,
+            pitch: Pitch::standard(),
+            rules: MatchRules {
+                half_length_ms: 45 * 60 * 1000,
+                extra_time_length_ms: 15 * 60 * 1000,
+                max_substitutions: 5,
+                ..MatchRules::default()
+            },
+        }
+    }
+}
+
+impl Default for MatchConfig {
+    fn default() -> Self {
+        Self::five_a_side()
+    }
+}
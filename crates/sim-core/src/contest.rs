@@ -0,0 +1,135 @@
+use crate::types::{Player, Vec2};
+use crate::stamina::performance_factor;
+
+/// 인터셉트를 시도할 수 있는 패스 경로와의 최대 거리 (m)
+pub const INTERCEPTION_RANGE: f32 = 2.0;
+/// 이 속도 이상의 패스는 거의 끊을 수 없음 (m/s)
+const UNREADABLE_PASS_SPEED: f32 = 35.0;
+
+/// 체력이 남아 있을수록 경합에서 유리 (0.6 ~ 1.0)
+fn stamina_factor(player: &Player) -> f32 {
+    performance_factor(player.stamina)
+}
+
+/// 태클 성공 확률
+///
+/// 공격성이 높을수록 적극적으로 발을 넣고, 규율이 높을수록 타이밍이 정확함.
+/// 볼 소유자는 자신감과 체력으로 공을 지켜냄
+pub fn tackle_success_probability(tackler: &Player, carrier: &Player) -> f32 {
+    let attack = (0.15 + 0.35 * tackler.persona.aggression + 0.15 * tackler.persona.discipline)
+        * stamina_factor(tackler);
+    let shield = 0.15 * carrier.persona.confidence * stamina_factor(carrier);
+    (attack - shield).clamp(0.05, 0.8)
+}
+
+/// 패스 경로 근처 수비수의 인터셉트 확률
+///
+/// 경로에 가까울수록, 공이 느릴수록, 위치 선정(규율)과 시야가 좋을수록 높음
+pub fn interception_probability(defender: &Player, distance_to_path: f32, ball_speed: f32) -> f32 {
+    if distance_to_path >= INTERCEPTION_RANGE {
+        return 0.0;
+    }
+    let closeness = 1.0 - distance_to_path / INTERCEPTION_RANGE;
+    let readability = (1.0 - ball_speed / UNREADABLE_PASS_SPEED).clamp(0.1, 1.0);
+    let anticipation = 0.4 + 0.3 * defender.persona.discipline + 0.3 * (defender.persona.vision_range / 40.0).min(1.0);
+    (closeness * readability * anticipation * stamina_factor(defender)).clamp(0.0, 0.9)
+}
+
+/// 태클이 반칙으로 선언될 확률
+///
+/// 실패한 태클은 늦게 들어간 경우가 많아 반칙 가능성이 높고,
+/// 공격성이 높고 규율이 낮을수록 거친 태클이 됨
+pub fn foul_probability(tackler: &Player, successful: bool) -> f32 {
+    let recklessness = tackler.persona.aggression * (1.0 - 0.6 * tackler.persona.discipline);
+    if successful {
+        0.02 + 0.12 * recklessness
+    } else {
+        0.08 + 0.4 * recklessness
+    }
+}
+
+/// 반칙에 대한 (경고, 퇴장) 확률
+pub fn card_probabilities(offender: &Player) -> (f32, f32) {
+    let recklessness = offender.persona.aggression * (1.0 - 0.6 * offender.persona.discipline);
+    let red = 0.01 + 0.05 * recklessness;
+    let yellow = 0.1 + 0.35 * recklessness;
+    (yellow, red)
+}
+
+/// 루즈볼 경합 가중치: 가깝고 빠른 선수가 먼저 공에 닿음
+pub fn loose_ball_weight(player: &Player, distance: f32, possession_range: f32) -> f32 {
+    let closeness = (possession_range - distance).max(0.0) / possession_range;
+    closeness * (0.5 + 0.5 * player.persona.work_rate) * stamina_factor(player)
+}
+
+/// 점과 선분 사이의 최단 거리
+pub fn distance_to_segment(point: &Vec2, start: &Vec2, end: &Vec2) -> f32 {
+    let dx = end.x - start.x;
+    let dy = end.y - start.y;
+    let length_sq = dx * dx + dy * dy;
+    if length_sq < 0.0001 {
+        return point.distance(start);
+    }
+    let t = (((point.x - start.x) * dx + (point.y - start.y) * dy) / length_sq).clamp(0.0, 1.0);
+    point.distance(&Vec2::new(start.x + dx * t, start.y + dy * t))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::Persona;
+
+    fn player(aggression: f32, discipline: f32, stamina: f32) -> Player {
+        Player {
+            id: 0,
+            team_id: 0,
+            role: "Player".to_string(),
+            position: Vec2::new(0.0, 0.0),
+            velocity: Vec2::default(),
+            stamina,
+            morale: 1.0,
+            has_ball: false,
+            persona: Persona {
+                aggression,
+                discipline,
+                ..Persona::default()
+            },
+        }
+    }
+
+    #[test]
+    fn aggressive_fresh_tacklers_win_more_often() {
+        let carrier = player(0.5, 0.5, 1.0);
+        let timid = tackle_success_probability(&player(0.1, 0.5, 1.0), &carrier);
+        let aggressive = tackle_success_probability(&player(0.9, 0.5, 1.0), &carrier);
+        let tired = tackle_success_probability(&player(0.9, 0.5, 0.1), &carrier);
+        assert!(aggressive > timid);
+        assert!(aggressive > tired);
+    }
+
+    #[test]
+    fn interceptions_favour_close_defenders_and_slow_passes() {
+        let defender = player(0.5, 0.8, 1.0);
+        assert!(interception_probability(&defender, 0.2, 10.0) > interception_probability(&defender, 1.5, 10.0));
+        assert!(interception_probability(&defender, 0.5, 8.0) > interception_probability(&defender, 0.5, 28.0));
+        assert_eq!(interception_probability(&defender, INTERCEPTION_RANGE, 8.0), 0.0);
+    }
+
+    #[test]
+    fn reckless_players_foul_and_get_booked_more() {
+        let careful = player(0.2, 0.9, 1.0);
+        let reckless = player(0.9, 0.1, 1.0);
+        assert!(foul_probability(&reckless, false) > foul_probability(&careful, false));
+        assert!(foul_probability(&reckless, false) > foul_probability(&reckless, true));
+        assert!(card_probabilities(&reckless).0 > card_probabilities(&careful).0);
+        assert!(card_probabilities(&reckless).1 > card_probabilities(&careful).1);
+    }
+
+    #[test]
+    fn segment_distance_clamps_to_endpoints() {
+        let start = Vec2::new(0.0, 0.0);
+        let end = Vec2::new(10.0, 0.0);
+        assert!((distance_to_segment(&Vec2::new(5.0, 2.0), &start, &end) - 2.0).abs() < 1e-5);
+        assert!((distance_to_segment(&Vec2::new(13.0, 4.0), &start, &end) - 5.0).abs() < 1e-5);
+    }
+}
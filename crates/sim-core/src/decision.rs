@@ -1,4 +1,12 @@
-use crate::types::{Player, Persona, Vec2};
+use crate::types::{Goal, Player, Persona, Vec2};
+use crate::physics::{defended_goal, TeamGrid};
+use crate::config::Pitch;
+use crate::morale::boldness;
+use crate::pitch_control::PitchControl;
+use crate::xg::{expected_goals, ShotSituation, ShotType};
+
+/// 슈팅 유틸리티로 바꿀 때 기대 득점의 제곱근에 곱하는 값
+const SHOOT_XG_WEIGHT: f32 = 0.8;
 
 /// 행동 타입
 #[derive(Debug, Clone, Copy)]
@@ -21,12 +29,13 @@ pub struct ActionScore {
 
 /// 유틸리티 계산 함수들
 
-/// 패스 유틸리티
+/// 패스 유틸리티 (`control`은 현재 경기장 통제 격자)
 pub fn pass_utility(
     player: &Player,
     target: &Vec2,
+    goal_position: &Vec2,
     _teammates: &[Player],
-    opponents: &[Player],
+    control: &PitchControl,
     persona: &Persona,
 ) -> f32 {
     let distance = player.position.distance(target);
@@ -39,52 +48,50 @@ pub fn pass_utility(
         1.0 - (distance / max_distance)
     };
     
-    // 위험 선호도 반영
+    // 위험 선호도 반영 (사기가 높으면 과감한 패스, 낮으면 안전한 패스 선호)
     let risk_factor = if distance > max_distance * 0.7 {
-        persona.risk_appetite
+        persona.risk_appetite * boldness(player.morale)
     } else {
-        1.0 - persona.risk_appetite
+        (1.0 - persona.risk_appetite) * (2.0 - boldness(player.morale))
     };
     
-    // 상대방 차단 위험도 계산
-    let blocking_risk = calculate_blocking_risk(&player.position, target, opponents);
-    let blocking_score = 1.0 - blocking_risk;
+    // 패스 성공 확률 (받는 지점과 패스 길의 경기장 통제)
+    let success = control.pass_success_probability(&player.position, target, player.team_id);
     
     // 전진 이득 (골대 방향으로 갈수록 높음)
-    let goal_direction = Vec2::new(FIELD_WIDTH / 2.0, FIELD_HEIGHT);
-    let forward_gain = calculate_forward_gain(&player.position, target, &goal_direction);
+    let forward_gain = calculate_forward_gain(&player.position, target, goal_position);
     
-    distance_score * risk_factor * blocking_score * (0.7 + 0.3 * forward_gain)
+    distance_score * risk_factor * success * (0.7 + 0.3 * forward_gain)
 }
 
-/// 슈팅 유틸리티
+/// 슈팅 유틸리티 (`opponents`는 상대 팀 선수 격자)
+///
+/// 필드 슈팅의 기대 득점에 자신감과 사기를 반영.
+/// 제곱근을 써서 낮은 xG 슈팅도 패스·보유와 경쟁할 수 있게 함
 pub fn shoot_utility(
     player: &Player,
-    goal_position: &Vec2,
-    opponents: &[Player],
+    goal: &Goal,
+    opponents: TeamGrid<'_>,
     persona: &Persona,
 ) -> f32 {
-    let distance = player.position.distance(goal_position);
+    let distance = player.position.distance(&goal.center);
     let max_shoot_distance = 20.0;
     
     if distance > max_shoot_distance {
         return 0.0;
     }
     
-    // 거리 점수
-    let distance_score = 1.0 - (distance / max_shoot_distance);
-    
-    // 각도 점수 (간단화: 골대 중심에 가까울수록 높음)
-    let angle_score = 1.0;
+    let xg = expected_goals(&ShotSituation {
+        position: player.position,
+        velocity: player.velocity,
+        pressure: calculate_defensive_pressure(&player.position, opponents),
+        shot_type: ShotType::Foot,
+    }, goal);
     
-    // 자신감 반영
-    let confidence_factor = persona.confidence;
+    // 자신감과 사기 반영
+    let confidence_factor = persona.confidence * boldness(player.morale);
     
-    // 수비 압박 계산
-    let defensive_pressure = calculate_defensive_pressure(&player.position, opponents);
-    let pressure_score = 1.0 - defensive_pressure;
-    
-    distance_score * angle_score * confidence_factor * pressure_score
+    xg.sqrt() * SHOOT_XG_WEIGHT * confidence_factor
 }
 
 /// 압박 유틸리티
@@ -114,28 +121,36 @@ pub fn press_utility(
     distance_score * pressing_factor * stamina_factor
 }
 
+/// 공격 방향 골대 중심 (전반 기준 홈 팀은 y = pitch.length 방향으로 공격)
+pub fn attacking_goal(pitch: &Pitch, team_id: u8, ends_swapped: bool) -> Vec2 {
+    defended_goal(pitch, 1 - team_id, ends_swapped).center
+}
+
 /// 최고 유틸리티 행동 선택
 pub fn select_best_action(scores: &[ActionScore]) -> Option<Action> {
     scores
         .iter()
-        .max_by(|a, b| a.utility.partial_cmp(&b.utility).unwrap())
+        .max_by(|a, b| a.utility.total_cmp(&b.utility))
         .map(|s| s.action)
 }
 
+/// 슈팅 각도 (도): 골대 정면 = 0, 골라인과 평행할수록 90에 가까움
+pub fn shot_angle(from: &Vec2, goal: &Vec2) -> f32 {
+    let dx = (goal.x - from.x).abs();
+    let dy = (goal.y - from.y).abs();
+    dx.atan2(dy).to_degrees()
+}
+
 // 헬퍼 함수들
-fn calculate_blocking_risk(
+
+/// 패스 경로 차단 위험도 (0.0 ~ 1.0)
+pub fn calculate_blocking_risk(
     from: &Vec2,
     to: &Vec2,
-    opponents: &[Player],
+    opponents: TeamGrid<'_>,
 ) -> f32 {
-    let mut risk: f32 = 0.0;
-    for opponent in opponents {
-        let dist_to_line = distance_to_line_segment(from, to, &opponent.position);
-        if dist_to_line < 2.0 {
-            risk += 0.3;
-        }
-    }
-    risk.min(1.0f32)
+    let blockers = opponents.near_segment(*from, *to, 2.0).count();
+    (blockers as f32 * 0.3).min(1.0f32)
 }
 
 fn calculate_forward_gain(
@@ -152,34 +167,13 @@ fn calculate_forward_gain(
     }
 }
 
-fn calculate_defensive_pressure(
+/// 주변 상대 선수로 인한 수비 압박 (0.0 ~ 1.0)
+pub fn calculate_defensive_pressure(
     position: &Vec2,
-    opponents: &[Player],
+    opponents: TeamGrid<'_>,
 ) -> f32 {
-    let mut pressure = 0.0;
-    for opponent in opponents {
-        let dist = position.distance(&opponent.position);
-        if dist < 5.0 {
-            pressure += (1.0 - dist / 5.0) * 0.3;
-        }
-    }
+    let pressure: f32 = opponents.within(*position, 5.0)
+        .map(|(_, opponent)| (1.0 - position.distance(&opponent) / 5.0) * 0.3)
+        .sum();
     pressure.min(1.0f32)
 }
-
-fn distance_to_line_segment(a: &Vec2, b: &Vec2, p: &Vec2) -> f32 {
-    let ab = Vec2::new(b.x - a.x, b.y - a.y);
-    let ap = Vec2::new(p.x - a.x, p.y - a.y);
-    let ab_squared = ab.x * ab.x + ab.y * ab.y;
-    
-    if ab_squared < 0.01 {
-        return a.distance(p);
-    }
-    
-    let t = ((ap.x * ab.x + ap.y * ab.y) / ab_squared).max(0.0).min(1.0);
-    let closest = Vec2::new(a.x + t * ab.x, a.y + t * ab.y);
-    closest.distance(p)
-}
-
-const FIELD_WIDTH: f32 = 68.0;
-const FIELD_HEIGHT: f32 = 105.0;
-
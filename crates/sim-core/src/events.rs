@@ -26,8 +26,11 @@ pub enum EventType {
     Turnover,
     SetPiece,
     Foul,
+    Offside,
+    Card,
     Save,
     Goal,
+    Substitution,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -42,18 +45,61 @@ pub enum EventPayload {
         distance: f32,
         angle: f32,
         on_target: bool,
+        /// 기대 득점
+        #[serde(default)]
+        xg: f32,
     },
     Tackle {
         on_player_id: String,
         successful: bool,
     },
+    Save {
+        shooter_id: String,
+        caught: bool,
+    },
     Goal {
         scorer_id: String,
         assist_id: Option<String>,
     },
+    SetPiece {
+        kind: SetPieceKind,
+    },
+    Foul {
+        on_player_id: String,
+        restart: SetPieceKind,
+    },
+    Card {
+        card: Card,
+        sent_off: bool,
+    },
+    Offside {
+        passer_id: String,
+    },
+    /// 이벤트 선수가 들어오고 replaced_player_id 선수가 나감
+    Substitution {
+        replaced_player_id: String,
+    },
     Empty,
 }
 
+/// 세트피스 종류
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SetPieceKind {
+    ThrowIn,
+    GoalKick,
+    Corner,
+    DirectFreeKick,
+    IndirectFreeKick,
+    Penalty,
+}
+
+/// 카드 종류
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Card {
+    Yellow,
+    Red,
+}
+
 #[derive(Debug, Clone, Copy, Serialize, Deserialize)]
 pub enum EventOutcome {
     Complete,
@@ -1,8 +1,87 @@
 use crate::types::*;
+use crate::config::MatchConfig;
 use crate::physics::*;
 use crate::decision::*;
 use crate::events::*;
+use crate::shootout::{self, ShootoutState};
+use crate::contest::*;
+use crate::stamina::{self, performance_factor};
+use crate::morale;
+use crate::tactics::{self, PlayPhase};
+use crate::behaviors;
+use crate::triggers::{self, TriggerFiring};
+use crate::substitution::{Substitution, SubstitutionError};
+use crate::pitch_control::PitchControl;
+use crate::stats::{IntentProgress, MatchStats, INTENT_REACHED_DISTANCE};
+use crate::xg::{expected_goals, ShotSituation, ShotType, PENALTY_XG};
+use crate::decision::Action as UtilityAction;
 use decision_plugin::{Intent, Action, IntentStatus};
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+/// 시드를 지정하지 않았을 때의 기본 시드
+pub const DEFAULT_SEED: u64 = 11;
+/// 고정 시뮬레이션 스텝 (10Hz)
+pub const TICK_MS: u64 = 100;
+/// 고정 스텝 길이 (초)
+pub const TICK_SECONDS: f32 = TICK_MS as f32 / 1000.0;
+
+/// 공을 찬 선수가 다시 소유할 수 없는 시간 (ms)
+const KICK_LOCKOUT_MS: u64 = 500;
+/// 압박 이벤트로 기록되는 볼 소유자와의 거리 (m)
+const PRESS_RANGE: f32 = 3.0;
+/// 세트피스 재개까지의 데드볼 시간 (ms)
+const RESTART_DELAY_MS: u64 = 2000;
+/// 세트피스 시 상대 팀이 떨어져야 하는 거리 (m)
+const RESTART_DISTANCE: f32 = 9.15;
+/// 골 이후 추가시간에 반영되는 데드볼 시간 (ms)
+const GOAL_STOPPAGE_MS: u64 = 30_000;
+/// 승부차기 킥 간격 (ms)
+const SHOOTOUT_KICK_INTERVAL_MS: u64 = 3000;
+/// 골키퍼가 공을 잡은 뒤 배급까지의 시간 (ms)
+const GK_HOLD_MS: u64 = 1500;
+/// 공을 잡은 뒤 바로 패스하기 전 첫 터치로 공을 다루는 시간 (ms)
+const FIRST_TOUCH_MS: u64 = 300;
+/// 골키퍼가 던져서 배급하는 최대 거리 (m)
+const GK_THROW_RANGE: f32 = 20.0;
+/// 골키퍼가 제자리에서 닿는 거리 (m)
+const GK_REACH: f32 = 1.5;
+/// 골키퍼 다이빙 속도 (m/s)
+const GK_DIVE_SPEED: f32 = 5.0;
+/// 골키퍼 반응 시간 (초)
+const GK_REACTION_SECONDS: f32 = 0.25;
+/// 골키퍼가 잡을 수 있는 최대 공 속도 (m/s)
+const GK_CATCH_SPEED: f32 = 22.0;
+/// 태클 실패 후 재시도까지의 시간 (ms)
+const TACKLE_COOLDOWN_MS: u64 = 2500;
+/// 이 거리 이상이면서 패스 길이 막혀 있으면 띄워서 패스 (m)
+const LOFT_MIN_DISTANCE: f32 = 15.0;
+/// 띄워서 넘길 만큼 패스 길이 막혔다고 보는 차단 위험도
+const LOFT_BLOCKING_RISK: f32 = 0.3;
+/// 작업량 1.0, 체력 1.0 기준 순항(조깅) 속도 (m/s)
+const PLAYER_CRUISE_SPEED: f32 = 5.0;
+/// 체력 1.0 기준 스프린트 최고 속도 (m/s)
+const PLAYER_SPRINT_SPEED: f32 = 7.5;
+/// 기준 가속도 (m/s²)
+const PLAYER_ACCELERATION: f32 = 4.0;
+/// 기준 감속도 (m/s²)
+const PLAYER_DECELERATION: f32 = 6.0;
+/// 최대 회전 속도 (rad/s)
+const PLAYER_TURN_RATE: f32 = 4.0;
+/// 작업량 1.0, 체력 1.0 기준 드리블 속도 (m/s)
+const DRIBBLE_SPEED: f32 = 3.5;
+/// 압박 역할 선수가 자기 자리에서 이 거리 안의 볼 소유자에게 달려듦 (m)
+const PRESS_ROLE_RANGE: f32 = 8.0;
+/// 압박 역할 선수가 볼 소유자 앞에 서는 거리 (m)
+const PRESS_ROLE_DISTANCE: f32 = 3.0;
+/// 압박 역할이 아닌 선수가 볼 소유자와 유지하는 거리 (m)
+const JOCKEY_DISTANCE: f32 = 4.0;
+/// 이 거리 이상에서 들어간 반칙 태클은 접촉 없는 방해로 보고 간접 프리킥 (m)
+const IMPEDING_DISTANCE: f32 = 1.35;
+/// 헤더로 보낸 공의 속도 (m/s)
+const HEADER_SPEED: f32 = 12.0;
+/// 헤더로 걷어내는 거리이자 헤더 패스를 받을 동료의 최대 거리 (m)
+const HEADER_RANGE: f32 = 15.0;
 
 /// 게임 월드 상태
 #[derive(Debug, Clone)]
@@ -13,84 +92,264 @@ pub struct GameWorld {
     pub events: Vec<MatchEvent>,
     /// 현재 플레이어 의도들 (LLM에서 생성)
     pub current_intents: Vec<Intent>,
+    /// 마지막으로 공을 찬 선수와 시점 (ms)
+    pub last_kick: Option<(u32, u64)>,
+    /// 현재 소유자가 공을 잡은 시점 (ms)
+    pub possession_since_ms: u64,
+    /// 마지막으로 공을 소유한 선수
+    pub last_touch: Option<u32>,
+    /// 진행 중인 패스 (받을 때까지 유지)
+    pub pending_pass: Option<PendingPass>,
+    /// 마지막으로 성공한 패스 (패스한 선수, 받은 선수) - 어시스트 판정용
+    pub last_completed_pass: Option<(u32, u32)>,
+    /// 현재 볼 소유자를 압박 중인 선수들
+    pub pressing_players: Vec<u32>,
+    /// 다음 이벤트 ID
+    pub next_event_id: u64,
+    /// 대기 중인 세트피스 (데드볼 상태)
+    pub pending_restart: Option<PendingRestart>,
+    /// 세트피스를 막 재개한 선수 (드리블 없이 바로 패스)
+    pub restart_taker: Option<u32>,
+    /// 키커가 아직 처리하지 않은 세트피스 종류 (오프사이드 면제 판정용)
+    pub restart_kind: Option<SetPieceKind>,
+    /// 태클 재시도 대기 (선수 ID, 재시도 가능 시점 ms)
+    pub tackle_cooldowns: Vec<(u32, u64)>,
+    /// 경기 시드 (재현성)
+    pub seed: u64,
+    /// 모든 확률적 결과에 사용되는 RNG
+    pub rng: ChaCha8Rng,
+    /// 아직 스텝으로 소비되지 않은 경기 시간 (초)
+    pub time_accumulator: f64,
+    /// 경기 형식 (인원, 경기장 규격, 규칙)
+    pub config: MatchConfig,
+    /// 팀별 전술 (포메이션, 역할, 트리거)
+    pub tactics: [Tactics; 2],
+    /// 발동된 전술 트리거 기록
+    pub trigger_log: Vec<TriggerFiring>,
+    /// 트리거 평가가 끝난 이벤트 수
+    pub trigger_event_cursor: usize,
+    /// 매 스텝 갱신되는 경기 통계
+    pub stats: MatchStats,
+    /// 이번 스텝 이동 후의 경기장 통제 (볼 액션 판단, 오버레이, LLM 컨텍스트가 함께 씀)
+    pub pitch_control: PitchControl,
+    /// 현재 피리어드의 누적 데드볼 시간 (ms, 추가시간 계산용)
+    pub dead_ball_ms: u64,
+    /// 골문으로 날아가는 중인 슈팅 (골키퍼 선방 판정용)
+    pub pending_shot: Option<PendingShot>,
+    /// 현재 패스에 대해 이미 인터셉트를 시도한 수비수
+    pub interception_attempts: Vec<u32>,
+    /// 경고를 받은 선수 ID
+    pub bookings: Vec<u32>,
+    /// 퇴장당한 선수 (경기장에서 제거됨)
+    pub sent_off: Vec<Player>,
+    /// 팀별 벤치 (교체 대기 선수)
+    pub bench: [Vec<Player>; 2],
+    /// 교체되어 나간 선수 (다시 들어올 수 없음)
+    pub substituted: Vec<Player>,
+    /// 팀별 실행된 교체 수
+    pub substitutions_made: [u8; 2],
+    /// 다음 데드볼에 실행할 교체
+    pub pending_substitutions: Vec<Substitution>,
+}
+
+/// 골문으로 날아가는 중인 슈팅
+#[derive(Debug, Clone, Copy)]
+pub struct PendingShot {
+    pub shooter_id: u32,
+    pub team_id: u8,
+    pub taken_at_ms: u64,
+    pub on_target: bool,
+}
+
+/// 데드볼 이후 재개 대기 중인 세트피스
+#[derive(Debug, Clone, Copy)]
+pub struct PendingRestart {
+    pub kind: SetPieceKind,
+    pub team_id: u8,
+    pub taker_id: u32,
+    pub location: Vec2,
+    /// 세트피스가 선언된 시점 (ms)
+    pub awarded_at_ms: u64,
+    /// 플레이 재개 시점 (ms)
+    pub resume_at_ms: u64,
+}
+
+/// 공중/지면을 이동 중인 패스 정보
+#[derive(Debug, Clone, Copy)]
+pub struct PendingPass {
+    pub passer_id: u32,
+    pub receiver_id: u32,
+    pub team_id: u8,
+    pub origin: Vec2,
+    pub distance: f32,
+    pub risk: f32,
+    /// 패스 순간 리시버가 오프사이드 위치였다면 그 위치
+    pub offside_at: Option<Vec2>,
+}
+
+/// 골키퍼는 넓은 시야로 침착하게 배급
+fn set_goalkeeper_persona(persona: &mut Persona) {
+    persona.vision_range = 35.0;
+    persona.patience = 0.7;
+}
+
+/// 작업량과 남은 체력에 따른 순항 속도 (m/s)
+fn cruise_speed(player: &Player) -> f32 {
+    PLAYER_CRUISE_SPEED * player.persona.work_rate * performance_factor(player.stamina)
+}
+
+/// 남은 체력에 따른 스프린트 최고 속도 (작업량이 높을수록 빠름, m/s)
+fn sprint_speed(player: &Player) -> f32 {
+    PLAYER_SPRINT_SPEED * (0.7 + 0.3 * player.persona.work_rate) * performance_factor(player.stamina)
+}
+
+/// 한 스텝 동안 이동한 거리 (세트피스/킥오프 재배치로 인한 순간이동은 제외)
+fn step_distance(position: &Vec2, previous: &Vec2, delta_time: f32) -> f32 {
+    let distance = position.distance(previous);
+    if distance > PLAYER_SPRINT_SPEED * delta_time * 1.5 { 0.0 } else { distance }
+}
+
+/// 선수의 이동 한계 (체력이 떨어지면 가속/감속도 둔해짐)
+fn movement_limits(player: &Player, max_speed: f32) -> MovementLimits {
+    let fitness = performance_factor(player.stamina);
+    MovementLimits {
+        max_speed,
+        acceleration: PLAYER_ACCELERATION * (0.6 + 0.4 * player.persona.work_rate) * fitness,
+        deceleration: PLAYER_DECELERATION * fitness,
+        turn_rate: PLAYER_TURN_RATE,
+    }
+}
+
+/// 볼 소유자의 행동 결정 결과
+#[derive(Debug, Clone, Copy)]
+struct BallAction {
+    action: UtilityAction,
+    target: Vec2,
+    receiver_id: Option<u32>,
 }
 
 impl GameWorld {
     pub fn new_5v5() -> Self {
+        Self::with_seed(DEFAULT_SEED)
+    }
+    
+    /// 시드를 지정한 5v5 경기 (같은 시드 + 같은 의도 → 같은 결과)
+    pub fn with_seed(seed: u64) -> Self {
+        Self::from_config(MatchConfig::five_a_side(), seed)
+    }
+    
+    /// 경기 형식에 맞춰 양 팀 선수를 배치한 경기
+    pub fn from_config(config: MatchConfig, seed: u64) -> Self {
         let mut players = Vec::new();
+        let team_size = config.team_size;
+        let tactics = [Tactics::default_for(team_size), Tactics::default_for(team_size)];
         
-        // 홈 팀 (5명) - 수직 배치
-        let home_positions = vec![
-            Vec2::new(10.0, 10.0),   // 수비
-            Vec2::new(10.0, 20.0),   // 수비
-            Vec2::new(20.0, 40.0),   // 미드필더
-            Vec2::new(30.0, 60.0),   // 공격
-            Vec2::new(50.0, 80.0),   // 공격
-        ];
-        
-        for (i, pos) in home_positions.iter().enumerate() {
-            let mut persona = Persona::default();
-            persona.risk_appetite = 0.3 + (i as f32 * 0.1);
-            
-            players.push(Player {
-                id: i as u32,
-                team_id: 0,
-                role: "Player".to_string(),
-                position: *pos,
-                stamina: 1.0,
-                morale: 0.7,
-                has_ball: false,
-                persona,
+        for team_id in 0..2u8 {
+            // 골키퍼는 골문 앞, 필드 플레이어는 포메이션 자리에 배치
+            let defends_low_end = team_id == team_defending_low_end(false);
+            let goal = defended_goal(&config.pitch, team_id, false);
+            let keeper = Vec2::new(goal.center.x, goal.center.y + goal.inward() * config.pitch.goal_area_depth * 0.5);
+            let outfield = (0..team_size.saturating_sub(1)).map(|slot| {
+                let center = config.pitch.center();
+                tactics::home_position(&config.pitch, &tactics[team_id as usize], slot, defends_low_end, &center, PlayPhase::Contested)
             });
-        }
-        
-        // 어웨이 팀 (5명)
-        let away_positions = vec![
-            Vec2::new(58.0, 95.0),   // 수비
-            Vec2::new(58.0, 85.0),   // 수비
-            Vec2::new(48.0, 65.0),   // 미드필더
-            Vec2::new(38.0, 45.0),   // 공격
-            Vec2::new(18.0, 25.0),   // 공격
-        ];
-        
-        for (i, pos) in away_positions.iter().enumerate() {
-            let mut persona = Persona::default();
-            persona.risk_appetite = 0.4 + (i as f32 * 0.1);
-            persona.pressing_intensity = 0.6;
+            let positions: Vec<Vec2> = std::iter::once(keeper).chain(outfield).collect();
             
-            players.push(Player {
-                id: (i + 5) as u32,
-                team_id: 1,
-                role: "Player".to_string(),
-                position: *pos,
-                stamina: 1.0,
-                morale: 0.7,
-                has_ball: false,
-                persona,
-            });
+            for (i, pos) in positions.into_iter().enumerate() {
+                // 뒤쪽 선수일수록 안전하게, 앞쪽 선수일수록 과감하게
+                let mut persona = Persona::default();
+                let depth = i as f32 / (team_size.max(2) - 1) as f32;
+                persona.risk_appetite = 0.3 + team_id as f32 * 0.1 + 0.4 * depth;
+                if team_id == 1 {
+                    persona.pressing_intensity = 0.6;
+                }
+                
+                players.push(Player {
+                    id: (team_id as usize * team_size + i) as u32,
+                    team_id,
+                    role: if i == 0 { ROLE_GOALKEEPER } else { ROLE_OUTFIELD }.to_string(),
+                    position: pos,
+                    velocity: Vec2::default(),
+                    stamina: 1.0,
+                    morale: morale::BASE_MORALE,
+                    has_ball: false,
+                    persona,
+                });
+            }
         }
         
-        let mut ball = Ball::new(FIELD_WIDTH / 2.0, FIELD_HEIGHT / 2.0);
-        
-        // 시작 시 첫 번째 홈 팀 플레이어에게 공 배치
-        if let Some(first_player) = players.first_mut() {
-            first_player.has_ball = true;
-            ball.owner = Some(first_player.id);
-            ball.position = first_player.position;
+        for player in players.iter_mut().filter(|p| p.is_goalkeeper()) {
+            set_goalkeeper_persona(&mut player.persona);
         }
         
-        Self {
+        // 벤치 선수 ID는 모든 선발 선수 다음부터
+        let bench = [0u8, 1].map(|team_id| {
+            (0..config.bench_size)
+                .map(|i| {
+                    let mut persona = Persona::default();
+                    // 선발 필드 플레이어 자리 순서대로 성향을 나눠 가짐
+                    let slot = 1 + i % (team_size.max(2) - 1);
+                    let depth = slot as f32 / (team_size.max(2) - 1) as f32;
+                    persona.risk_appetite = 0.3 + team_id as f32 * 0.1 + 0.4 * depth;
+                    if team_id == 1 {
+                        persona.pressing_intensity = 0.6;
+                    }
+                    Player {
+                        id: (2 * team_size + team_id as usize * config.bench_size + i) as u32,
+                        team_id,
+                        role: ROLE_OUTFIELD.to_string(),
+                        position: Vec2::default(),
+                        velocity: Vec2::default(),
+                        stamina: 1.0,
+                        morale: morale::BASE_MORALE,
+                        has_ball: false,
+                        persona,
+                    }
+                })
+                .collect()
+        });
+        
+        let pitch_control = PitchControl::compute(&config.pitch, &players);
+        let mut world = Self {
             players,
-            ball,
-            match_state: MatchState {
-                period: Period::H1,
-                time_ms: 0,
-                home_score: 0,
-                away_score: 0,
-            },
+            ball: Ball::new(config.pitch.width / 2.0, config.pitch.length / 2.0),
+            match_state: MatchState::new(),
             events: Vec::new(),
             current_intents: Vec::new(),
-        }
+            last_kick: None,
+            possession_since_ms: 0,
+            last_touch: None,
+            pending_pass: None,
+            last_completed_pass: None,
+            pressing_players: Vec::new(),
+            next_event_id: 0,
+            pending_restart: None,
+            restart_taker: None,
+            restart_kind: None,
+            tackle_cooldowns: Vec::new(),
+            seed,
+            rng: ChaCha8Rng::seed_from_u64(seed),
+            time_accumulator: 0.0,
+            config,
+            tactics,
+            trigger_log: Vec::new(),
+            trigger_event_cursor: 0,
+            stats: MatchStats::default(),
+            pitch_control,
+            dead_ball_ms: 0,
+            pending_shot: None,
+            interception_attempts: Vec::new(),
+            bookings: Vec::new(),
+            sent_off: Vec::new(),
+            bench,
+            substituted: Vec::new(),
+            substitutions_made: [0, 0],
+            pending_substitutions: Vec::new(),
+        };
+        
+        // 홈 팀 킥오프로 시작
+        world.restart_kickoff(0);
+        world
     }
     
     /// 의도 업데이트 (LLM에서 생성된 ActionPlan에서 호출)
@@ -115,90 +374,277 @@ impl GameWorld {
         }
     }
     
-    /// 플레이어의 의도에 따른 목표 위치 계산
-    fn get_target_from_intent(
-        &self,
-        player_id: u32,
-        intent: &Intent,
-    ) -> Option<Vec2> {
+    /// 플레이어의 의도에 따른 목표 위치와 스프린트 여부
+    ///
+    /// 목표를 정할 수 없으면 (대상 선수가 없거나 행동이 없으면) None
+    fn intent_target(&self, player: &Player, intent: &Intent, grid: &SpatialGrid) -> Option<(Vec2, bool)> {
+        let pitch = &self.config.pitch;
+        let ends_swapped = self.match_state.ends_swapped;
+        let own_goal = defended_goal(pitch, player.team_id, ends_swapped).center;
+        let carrier = self.ball.owner.and_then(|id| self.players.iter().find(|p| p.id == id));
+        let teammate_carrier = carrier.filter(|c| c.team_id == player.team_id && c.id != player.id);
+        let opponent_carrier = carrier.filter(|c| c.team_id != player.team_id);
+        let opponents = || {
+            self.players.iter()
+                .filter(|p| p.team_id != player.team_id)
+                .map(|p| p.position)
+                .collect::<Vec<Vec2>>()
+        };
+        
         match &intent.action {
-            Some(Action::AttackSpace { target }) => Some(Vec2::new(target.x, target.y)),
-            Some(Action::Press { target }) => Some(Vec2::new(target.x, target.y)),
-            Some(Action::MoveToBall) => Some(self.ball.position),
-            Some(Action::ReturnToPosition { position }) => Some(Vec2::new(position.x, position.y)),
-            Some(Action::BlockSpace { target }) => Some(Vec2::new(target.x, target.y)),
-            Some(Action::MarkPlayer { target_id }) => {
-                // 대상 플레이어 위치 찾기
-                self.players.iter()
-                    .find(|p| p.id == *target_id)
-                    .map(|p| p.position)
+            Some(Action::AttackSpace { target }) => {
+                let target = Vec2::new(target.x, target.y);
+                let attacked_goal = defended_goal(pitch, 1 - player.team_id, ends_swapped);
+                let line_depth = self.config.rules.offside
+                    .then(|| defensive_line_depth(&opponents(), &attacked_goal));
+                Some(behaviors::attacking_run(&target, &attacked_goal, line_depth, teammate_carrier.map(|c| &c.position)))
             }
-            Some(Action::FindPassOption) | Some(Action::HoldPosition) => {
-                // 현재 위치 유지
-                self.players.iter()
-                    .find(|p| p.id == player_id)
-                    .map(|p| p.position)
+            Some(Action::Press { target }) => {
+                // 압박 지점 근처에 상대 소유자가 오면 달려들고, 아니면 지점에서 대기
+                let zone = Vec2::new(target.x, target.y);
+                match opponent_carrier.filter(|c| c.position.distance(&zone) < behaviors::PRESS_ENGAGE_RANGE) {
+                    Some(carrier) => {
+                        let shadowed = self.most_dangerous_receiver(carrier, &own_goal);
+                        Some((behaviors::press_position(&carrier.position, &own_goal, shadowed.as_ref()), true))
+                    }
+                    None => Some((zone, false)),
+                }
+            }
+            Some(Action::MoveToBall) => Some((self.ball.position, true)),
+            Some(Action::ReturnToPosition { position }) => Some((Vec2::new(position.x, position.y), false)),
+            Some(Action::BlockSpace { target }) => {
+                let zone = Vec2::new(target.x, target.y);
+                Some((behaviors::block_position(&self.ball.position, &zone), false))
+            }
+            Some(Action::MarkPlayer { target_id }) => {
+                let target = self.players.iter().find(|p| p.id == *target_id && p.team_id != player.team_id)?;
+                let position = behaviors::mark_position(&target.position, &self.ball.position, &own_goal);
+                // 대상에게서 떨어지면 스프린트로 따라붙음
+                let sprint = player.position.distance(&position) > behaviors::MARK_DISTANCE * 2.0;
+                Some((position, sprint))
             }
+            Some(Action::FindPassOption) => match teammate_carrier {
+                Some(carrier) => {
+                    let opponents = grid.team(1 - player.team_id);
+                    Some((behaviors::pass_option_position(pitch, &player.position, &carrier.position, opponents), false))
+                }
+                None => Some((self.home_position(player), false)),
+            },
+            Some(Action::HoldPosition) => Some((self.home_position(player), false)),
             None => None,
         }
     }
     
-    /// 게임 틱 업데이트 (10Hz)
-    pub fn tick(&mut self, delta_time: f32) {
-        self.match_state.time_ms += (delta_time * 1000.0) as u64;
+    /// 볼 소유자의 패스 대상 중 우리 골문에 가장 가까운 선수 위치 (커버 섀도우 대상)
+    fn most_dangerous_receiver(&self, carrier: &Player, own_goal: &Vec2) -> Option<Vec2> {
+        self.players.iter()
+            .filter(|p| p.team_id == carrier.team_id && p.id != carrier.id && !p.is_goalkeeper())
+            .filter(|p| p.position.distance(&carrier.position) < behaviors::PASS_OPTION_MAX_DISTANCE)
+            .map(|p| p.position)
+            .min_by(|a, b| a.distance(own_goal).total_cmp(&b.distance(own_goal)))
+    }
+    
+    /// 벽시계 시간만큼 경기 진행 (고정 스텝 누적기)
+    ///
+    /// 배속은 스텝 크기가 아닌 스텝 수를 바꾸므로 결과가 프레임레이트와 무관함.
+    /// 실행한 스텝 수를 반환
+    pub fn advance(&mut self, wall_clock_seconds: f64, speed_multiplier: f64) -> u32 {
+        self.time_accumulator += wall_clock_seconds * speed_multiplier.max(0.0);
+        
+        // 부동소수 누적 오차로 스텝이 하나 밀리지 않도록 작은 허용치 적용
+        let step_seconds = TICK_MS as f64 / 1000.0;
+        let mut steps = 0;
+        while self.time_accumulator + 1e-9 >= step_seconds {
+            self.time_accumulator = (self.time_accumulator - step_seconds).max(0.0);
+            self.tick();
+            steps += 1;
+        }
+        steps
+    }
+    
+    /// 게임 틱 업데이트 (고정 10Hz 스텝)
+    pub fn tick(&mut self) {
+        if self.match_state.is_finished() {
+            return;
+        }
+        
+        self.match_state.time_ms += TICK_MS;
+        self.match_state.period_elapsed_ms += TICK_MS;
+        if self.match_state.phase == MatchPhase::Shootout {
+            self.step_shootout();
+            return;
+        }
+        let dead_ball = self.pending_restart.is_some();
+        if dead_ball {
+            self.dead_ball_ms += TICK_MS;
+            self.apply_pending_substitutions();
+        }
         
+        let previous_positions: Vec<(u32, Vec2)> = self.players.iter().map(|p| (p.id, p.position)).collect();
+        // 선수 위치 격자는 스텝마다 한 번 만들어 플레이 진행과 통계 집계에 함께 씀
+        let mut grid = SpatialGrid::from_players(&self.config.pitch, &self.players);
+        self.step_play(&mut grid, TICK_SECONDS);
+        grid.refresh(&self.players);
+        self.evaluate_triggers();
+        self.update_stamina(&previous_positions, dead_ball, TICK_SECONDS);
+        self.update_stats(&grid, &previous_positions, dead_ball, TICK_SECONDS);
+        self.update_morale_drift(TICK_SECONDS);
+        self.update_period();
+    }
+    
+    /// 스코어에 따라 팀 사기가 서서히 변함
+    fn update_morale_drift(&mut self, delta_time: f32) {
+        let home_lead = self.match_state.home_score as i32 - self.match_state.away_score as i32;
+        for player in &mut self.players {
+            let goal_difference = if player.team_id == 0 { home_lead } else { -home_lead };
+            player.morale = morale::drift_towards(player.morale, morale::scoreline_target(goal_difference), delta_time);
+        }
+    }
+    
+    /// 팀 전원의 사기 변화
+    fn swing_team_morale(&mut self, team_id: u8, swing: f32) {
+        for player in self.players.iter_mut().filter(|p| p.team_id == team_id) {
+            player.morale = morale::apply_swing(player.morale, swing, player.persona.confidence);
+        }
+    }
+    
+    /// 이번 스텝의 이동 거리에 따라 체력 소모/회복
+    fn update_stamina(&mut self, previous_positions: &[(u32, Vec2)], dead_ball: bool, delta_time: f32) {
+        for player in &mut self.players {
+            let Some((_, previous)) = previous_positions.iter().find(|(id, _)| *id == player.id) else {
+                continue;
+            };
+            let max_speed = sprint_speed(player);
+            let distance = step_distance(&player.position, previous, delta_time);
+            player.stamina = stamina::update_stamina(
+                player.stamina,
+                distance,
+                max_speed,
+                player.persona.work_rate,
+                delta_time,
+                dead_ball,
+            );
+        }
+    }
+    
+    /// 이번 스텝의 이벤트, 점유, 이동 거리, 의도 진행을 통계에 반영
+    fn update_stats(&mut self, grid: &SpatialGrid, previous_positions: &[(u32, Vec2)], dead_ball: bool, delta_time: f32) {
+        self.stats.record_events(&self.events);
+        if !dead_ball {
+            if let Some(team_id) = self.last_touch_team() {
+                self.stats.record_possession(team_id, TICK_MS);
+            }
+        }
+        for player in &self.players {
+            if let Some((_, previous)) = previous_positions.iter().find(|(id, _)| *id == player.id) {
+                self.stats.record_distance(player.id, player.team_id, step_distance(&player.position, previous, delta_time));
+            }
+        }
+        let intents: Vec<IntentProgress> = self.current_intents.iter()
+            .filter(|intent| intent.action.is_some())
+            .filter_map(|intent| {
+                let player = self.players.iter().find(|p| p.id == intent.player_id)?;
+                let reached = self.intent_target(player, intent, grid)
+                    .is_some_and(|(target, _)| player.position.distance(&target) < INTENT_REACHED_DISTANCE);
+                Some(IntentProgress {
+                    player_id: player.id,
+                    team_id: player.team_id,
+                    created_at_ms: intent.created_at_ms,
+                    reached,
+                })
+            })
+            .collect();
+        self.stats.record_intents(&intents);
+    }
+    
+    /// 한 스텝의 플레이 진행 (이동, 소유권, 볼 액션)
+    ///
+    /// `grid`는 이번 스텝의 선수 위치 격자로, 이동 후 다시 채워 소유권과 볼 액션 판단에 씀
+    fn step_play(&mut self, grid: &mut SpatialGrid, delta_time: f32) {
         // 만료된 의도 제거
         self.current_intents.retain(|intent| {
             !intent.is_expired(self.match_state.time_ms)
         });
         
         // 1. 의도 기반 플레이어 이동
-        let current_positions: Vec<Vec2> = self.players.iter().map(|p| p.position).collect();
         let mut new_positions = Vec::new();
+        let chasers = self.loose_ball_chasers();
         
         for (i, player) in self.players.iter().enumerate() {
-            let target = if let Some(intent) = self.current_intents.iter()
+            // 공을 다투거나 압박할 때, 골키퍼가 반응할 때는 스프린트
+            let (target, sprint) = if let Some(restart_target) = self.restart_position(player) {
+                // 세트피스 대기 중 재배치
+                (restart_target, false)
+            } else if player.is_goalkeeper() {
+                // 골키퍼는 의도와 관계없이 골문을 지킴
+                (self.goalkeeper_target(player), true)
+            } else if chasers.contains(&player.id) {
+                // 루즈볼은 의도와 관계없이 팀에서 가장 가까운 선수가 경합
+                (self.ball.position, true)
+            } else if let Some(intent_target) = self.current_intents.iter()
                 .find(|intent| intent.player_id == player.id)
+                .and_then(|intent| self.intent_target(player, intent, grid))
             {
                 // 의도가 있으면 의도에 따라 목표 결정
-                if let Some(target_pos) = self.get_target_from_intent(player.id, intent) {
-                    target_pos
-                } else {
-                    // 의도가 있지만 목표를 계산할 수 없으면 현재 위치 유지
-                    player.position
-                }
+                intent_target
+            } else if self.pending_restart.is_some() {
+                (player.position, false)
             } else {
-                // 의도가 없으면 기본 동작 (공을 향해 이동하거나 위치 복귀)
-                if self.ball.owner.is_none() {
-                    self.ball.position
-                } else {
-                    // 기본 위치로 복귀
-                    let base_x = if player.team_id == 0 { 10.0 } else { 58.0 };
-                    Vec2::new(base_x, player.position.y)
-                }
+                // 의도가 없거나 목표를 정할 수 없으면 압박 또는 포메이션 위치 복귀
+                let home = self.home_position(player);
+                self.closing_down_target(player, home).unwrap_or((home, false))
             };
             
-            let max_speed = 5.0 * player.persona.work_rate;
-            let other_positions: Vec<Vec2> = current_positions.iter()
-                .enumerate()
+            let max_speed = if sprint { sprint_speed(player) } else { cruise_speed(player) };
+            let (new_pos, new_velocity) = steer_towards(
+                player.position,
+                player.velocity,
+                target,
+                &movement_limits(player, max_speed),
+                delta_time,
+            );
+            let nearby = grid.within(new_pos, MIN_DISTANCE)
                 .filter(|(j, _)| *j != i)
-                .map(|(_, pos)| *pos)
-                .collect();
-            
-            let new_pos = move_towards(player.position, target, max_speed, delta_time);
-            new_positions.push(avoid_collision(new_pos, &other_positions, MIN_DISTANCE));
+                .map(|(_, pos)| pos);
+            new_positions.push((avoid_collision(new_pos, nearby, MIN_DISTANCE), new_velocity));
         }
         
         // 위치 업데이트
-        for (player, new_pos) in self.players.iter_mut().zip(new_positions.iter()) {
+        for (player, (new_pos, new_velocity)) in self.players.iter_mut().zip(new_positions.iter()) {
             if !player.has_ball {
                 player.position = *new_pos;
+                player.velocity = *new_velocity;
+            }
+        }
+        grid.refresh(&self.players);
+        self.pitch_control = PitchControl::compute(&self.config.pitch, &self.players);
+        
+        // 세트피스 대기 중에는 공이 정지 상태
+        if let Some(restart) = self.pending_restart {
+            if self.match_state.time_ms >= restart.resume_at_ms {
+                self.resume_from_restart(restart, grid);
             }
+            return;
         }
         
         // 2. 공 소유권 업데이트
-        if let Some(owner_id) = check_ball_ownership(&self.ball, &self.players, 1.5) {
+        let previous_owner = self.ball.owner;
+        self.attempt_tackles();
+        if self.pending_restart.is_some() {
+            // 반칙으로 경기 중단
+            return;
+        }
+        let excluded = self.last_kick
+            .filter(|(_, kicked_at)| self.match_state.time_ms < kicked_at + KICK_LOCKOUT_MS)
+            .map(|(kicker_id, _)| kicker_id);
+        if let Some(owner_id) = check_ball_ownership(&self.ball, &self.players, grid, POSSESSION_RANGE, excluded, &mut self.rng) {
+            if self.ball.owner != Some(owner_id) {
+                self.possession_since_ms = self.match_state.time_ms;
+            }
             self.ball.owner = Some(owner_id);
+            self.ball.velocity = Vec2::new(0.0, 0.0);
+            self.ball.height = 0.0;
+            self.ball.vertical_velocity = 0.0;
             for player in &mut self.players {
                 player.has_ball = player.id == owner_id;
                 if player.has_ball {
@@ -207,20 +653,2415 @@ impl GameWorld {
             }
         } else {
             // 공이 자유롭게 움직임
-            update_ball(&mut self.ball, delta_time, 0.95);
+            self.ball.owner = None;
+            for player in &mut self.players {
+                player.has_ball = false;
+            }
+            let previous_ball_position = self.ball.position;
+            update_ball(&mut self.ball, delta_time);
+            self.attempt_save(previous_ball_position);
+            
+            if self.attempt_header(grid) {
+                return;
+            }
+            if self.attempt_interceptions(previous_ball_position) {
+                self.record_possession_change(previous_owner, self.ball.owner);
+                return;
+            }
+            if let Some(conceding_team) = check_goal(&self.config.pitch, &self.ball, self.match_state.ends_swapped) {
+                self.award_goal(conceding_team);
+                return;
+            }
+            if let Some(exit) = check_out_of_play(&self.config.pitch, &self.ball, self.match_state.ends_swapped) {
+                self.award_restart(exit);
+                return;
+            }
+        }
+        self.record_possession_change(previous_owner, self.ball.owner);
+        self.record_presses();
+        
+        // 3. 볼 소유자 행동 처리 (드리블, 패스, 슈팅)
+        if let Some(owner_id) = self.ball.owner {
+            if let Some(ball_action) = self.choose_ball_action(owner_id, grid) {
+                self.execute_ball_action(owner_id, ball_action, grid, delta_time);
+            }
         }
+    }
+    
+    /// 이벤트 기록
+    fn push_event(
+        &mut self,
+        event_type: EventType,
+        player: &Player,
+        payload: EventPayload,
+        outcome: EventOutcome,
+    ) {
+        let event = MatchEvent {
+            id: format!("evt-{:06}", self.next_event_id),
+            t_ms: self.match_state.time_ms,
+            period: format!("{:?}", self.match_state.period),
+            event_type,
+            team_id: player.team_id.to_string(),
+            player_id: player.id.to_string(),
+            location: player.position,
+            payload,
+            outcome,
+        };
+        self.next_event_id += 1;
         
-        // 3. 의도 기반 특수 동작 처리 (FindPassOption 등)
-        // TODO: 패스, 슈팅 등 고급 액션 처리
+        // 이벤트를 일으킨 선수의 사기 변화
+        let swing = morale::event_swing(&event);
+        if let Some(actor) = self.players.iter_mut().find(|p| p.id == player.id) {
+            actor.morale = morale::apply_swing(actor.morale, swing, actor.persona.confidence);
+        }
+        self.events.push(event);
     }
     
-    /// 홈 팀 플레이어들
-    pub fn home_players(&self) -> Vec<&Player> {
-        self.players.iter().filter(|p| p.team_id == 0).collect()
+    fn find_player(&self, player_id: u32) -> Option<Player> {
+        self.players.iter().find(|p| p.id == player_id).cloned()
     }
     
-    /// 어웨이 팀 플레이어들
-    pub fn away_players(&self) -> Vec<&Player> {
-        self.players.iter().filter(|p| p.team_id == 1).collect()
+    /// 소유권 변화를 패스/태클/인터셉트/턴오버 이벤트로 기록
+    fn record_possession_change(&mut self, previous_owner: Option<u32>, new_owner: Option<u32>) {
+        let Some(new_id) = new_owner else {
+            return;
+        };
+        if previous_owner == Some(new_id) {
+            return;
+        }
+        self.pending_shot = None;
+        let Some(winner) = self.find_player(new_id) else {
+            return;
+        };
+        let loser = self.last_touch.and_then(|id| self.find_player(id));
+        self.last_touch = Some(new_id);
+        
+        if let Some(pass) = self.pending_pass.take() {
+            // 오프사이드 위치에 있던 리시버가 공을 받으면 반칙
+            if let Some(offside_at) = pass.offside_at.filter(|_| new_id == pass.receiver_id) {
+                self.call_offside(pass, winner, offside_at);
+                return;
+            }
+            
+            // 진행 중이던 패스의 결과
+            let completed = winner.team_id == pass.team_id;
+            self.last_completed_pass = completed.then_some((pass.passer_id, new_id));
+            if let Some(passer) = self.find_player(pass.passer_id) {
+                let mut location_player = passer.clone();
+                location_player.position = pass.origin;
+                self.push_event(
+                    EventType::Pass,
+                    &location_player,
+                    EventPayload::Pass {
+                        target_player_id: pass.receiver_id.to_string(),
+                        distance: pass.distance,
+                        risk: pass.risk,
+                    },
+                    if completed { EventOutcome::Complete } else { EventOutcome::Incomplete },
+                );
+            }
+            if !completed {
+                self.push_event(EventType::Interception, &winner, EventPayload::Empty, EventOutcome::Success);
+            }
+        } else if let (Some(previous_id), Some(loser)) = (previous_owner, loser.as_ref()) {
+            // 소유 중인 공을 상대가 빼앗음
+            if loser.team_id != winner.team_id {
+                self.push_event(
+                    EventType::Tackle,
+                    &winner,
+                    EventPayload::Tackle {
+                        on_player_id: previous_id.to_string(),
+                        successful: true,
+                    },
+                    EventOutcome::Success,
+                );
+            }
+        }
+        
+        if let Some(loser) = loser {
+            if loser.team_id != winner.team_id {
+                self.last_completed_pass = None;
+                self.push_event(EventType::Turnover, &loser, EventPayload::Empty, EventOutcome::Failure);
+            }
+        }
+    }
+    
+    /// 골 처리: 득점 기록, 골 이벤트, 센터 서클 킥오프
+    fn award_goal(&mut self, conceding_team: u8) {
+        let scoring_team = 1 - conceding_team;
+        if scoring_team == 0 {
+            self.match_state.home_score += 1;
+        } else {
+            self.match_state.away_score += 1;
+        }
+        
+        if let Some(scorer) = self.last_touch.and_then(|id| self.find_player(id)) {
+            // 자책골에는 어시스트 없음
+            let assist_id = self.last_completed_pass
+                .filter(|(_, receiver_id)| *receiver_id == scorer.id && scorer.team_id == scoring_team)
+                .map(|(passer_id, _)| passer_id.to_string());
+            self.push_event(
+                EventType::Goal,
+                &scorer,
+                EventPayload::Goal {
+                    scorer_id: scorer.id.to_string(),
+                    assist_id,
+                },
+                EventOutcome::Success,
+            );
+        }
+        
+        self.swing_team_morale(scoring_team, morale::GOAL_FOR_SWING);
+        self.swing_team_morale(conceding_team, morale::GOAL_AGAINST_SWING);
+        if let Some(scorer) = self.last_touch.and_then(|id| self.players.iter_mut().find(|p| p.id == id)) {
+            if scorer.team_id == scoring_team {
+                scorer.morale = morale::apply_swing(scorer.morale, morale::SCORER_SWING, scorer.persona.confidence);
+            }
+        }
+        
+        self.dead_ball_ms += GOAL_STOPPAGE_MS;
+        self.restart_kickoff(conceding_team);
+    }
+    
+    /// 정규 시간 종료 시 추가시간 선언, 추가시간 종료 시 피리어드 전환
+    fn update_period(&mut self) {
+        let state = &mut self.match_state;
+        let regulation_ms = self.config.rules.period_length_ms(state.period);
+        if state.period_elapsed_ms < regulation_ms {
+            return;
+        }
+        
+        // 추가시간: 데드볼 시간의 절반을 분 단위로 올림
+        let stoppage_ms = *state.stoppage_ms.get_or_insert_with(|| {
+            let minutes = (self.dead_ball_ms / 2).div_ceil(60_000);
+            (minutes * 60_000).min(self.config.rules.max_stoppage_ms)
+        });
+        if state.period_elapsed_ms < regulation_ms + stoppage_ms {
+            return;
+        }
+        
+        let level = state.home_score == state.away_score;
+        match state.period {
+            Period::H1 => self.start_period(Period::H2),
+            Period::H2 if level && self.config.rules.extra_time => self.start_period(Period::ExtraTime),
+            Period::H2 | Period::ExtraTime if level && self.config.rules.penalty_shootout => self.start_shootout(),
+            Period::H2 | Period::ExtraTime | Period::Penalties => self.finish_match(),
+        }
+    }
+    
+    /// 새 피리어드 시작: 진영 교체 후 킥오프
+    fn start_period(&mut self, period: Period) {
+        let state = &mut self.match_state;
+        state.period = period;
+        state.period_elapsed_ms = 0;
+        state.stoppage_ms = None;
+        self.dead_ball_ms = 0;
+        
+        // 전반은 홈 팀, 후반은 어웨이 팀이 킥오프. 연장전은 전반과 같은 진영
+        let (ends_swapped, kicking_team) = match period {
+            Period::H1 | Period::ExtraTime | Period::Penalties => (false, 0),
+            Period::H2 => (true, 1),
+        };
+        if state.ends_swapped != ends_swapped {
+            state.ends_swapped = ends_swapped;
+            // 진영 교체: 선수 위치를 경기장 중심 기준으로 뒤집음
+            for player in &mut self.players {
+                player.position.y = self.config.pitch.length - player.position.y;
+                player.velocity = Vec2::default();
+            }
+        }
+        // 하프타임/연장 전 휴식
+        let recovery = match period {
+            Period::H2 => stamina::HALF_TIME_RECOVERY,
+            Period::ExtraTime => stamina::EXTRA_TIME_RECOVERY,
+            Period::H1 | Period::Penalties => 0.0,
+        };
+        for player in &mut self.players {
+            player.stamina = (player.stamina + recovery).min(1.0);
+        }
+        
+        self.current_intents.clear();
+        self.restart_kickoff(kicking_team);
+    }
+    
+    /// 승부차기 시작: 팀별 키커 순서 결정
+    fn start_shootout(&mut self) {
+        let mut home_takers = shootout::order_takers(&self.home_players());
+        let mut away_takers = shootout::order_takers(&self.away_players());
+        // 퇴장으로 인원이 다르면 많은 쪽에서 순서가 늦은 선수를 빼 같은 수로 맞춤
+        let takers = home_takers.len().min(away_takers.len());
+        home_takers.truncate(takers);
+        away_takers.truncate(takers);
+        
+        let state = &mut self.match_state;
+        state.period = Period::Penalties;
+        state.phase = MatchPhase::Shootout;
+        state.period_elapsed_ms = 0;
+        state.stoppage_ms = None;
+        state.shootout = Some(ShootoutState::new(
+            home_takers,
+            away_takers,
+            state.time_ms + SHOOTOUT_KICK_INTERVAL_MS,
+        ));
+        
+        self.pending_restart = None;
+        self.pending_pass = None;
+        self.current_intents.clear();
+        self.ball.velocity = Vec2::new(0.0, 0.0);
+        self.ball.owner = None;
+        for player in &mut self.players {
+            player.has_ball = false;
+        }
+    }
+    
+    /// 승부차기 한 스텝: 킥 시점이 되면 킥 진행
+    fn step_shootout(&mut self) {
+        let due = self.match_state.shootout.as_ref()
+            .is_some_and(|state| self.match_state.time_ms >= state.next_kick_at_ms);
+        if due {
+            self.take_shootout_kick();
+        }
+    }
+    
+    /// 남은 승부차기를 즉시 모두 진행 (헤드리스 실행용)
+    pub fn resolve_shootout(&mut self) {
+        while self.match_state.phase == MatchPhase::Shootout {
+            self.take_shootout_kick();
+        }
+    }
+    
+    /// 승부차기 킥 한 번 처리
+    fn take_shootout_kick(&mut self) {
+        let Some(state) = self.match_state.shootout.as_ref() else {
+            self.finish_match();
+            return;
+        };
+        let kicking_team = state.next_team;
+        let Some(taker) = state.next_taker().and_then(|id| self.find_player(id)) else {
+            self.finish_match();
+            return;
+        };
+        let keeper = self.goalkeeper(1 - kicking_team);
+        
+        // 승부차기는 y = 0 쪽 골대에서 진행
+        let goal = defended_goal(&self.config.pitch, team_defending_low_end(false), false);
+        let spot = Vec2::new(goal.center.x, goal.center.y + self.config.pitch.penalty_spot_distance);
+        for player in &mut self.players {
+            if player.id == taker.id {
+                player.position = spot;
+            } else if Some(player.id) == keeper.as_ref().map(|k| k.id) {
+                player.position = goal.center;
+            }
+        }
+        self.ball = Ball::new(spot.x, spot.y);
+        
+        let on_target = self.rng.gen::<f32>() < shootout::on_target_probability(&taker);
+        let saved = on_target && self.rng.gen::<f32>() < shootout::save_probability(&taker, keeper.as_ref());
+        let scored = on_target && !saved;
+        
+        let mut taker_at_spot = taker.clone();
+        taker_at_spot.position = spot;
+        self.push_event(
+            EventType::Shot,
+            &taker_at_spot,
+            EventPayload::Shot {
+                distance: self.config.pitch.penalty_spot_distance,
+                angle: 0.0,
+                on_target,
+                xg: PENALTY_XG,
+            },
+            if scored { EventOutcome::Success } else { EventOutcome::Failure },
+        );
+        if saved {
+            if let Some(mut keeper) = keeper {
+                keeper.position = goal.center;
+                self.push_event(
+                    EventType::Save,
+                    &keeper,
+                    EventPayload::Save {
+                        shooter_id: taker.id.to_string(),
+                        caught: false,
+                    },
+                    EventOutcome::Success,
+                );
+            }
+        } else if scored {
+            self.push_event(
+                EventType::Goal,
+                &taker_at_spot,
+                EventPayload::Goal {
+                    scorer_id: taker.id.to_string(),
+                    assist_id: None,
+                },
+                EventOutcome::Success,
+            );
+        }
+        
+        let time_ms = self.match_state.time_ms;
+        let Some(state) = self.match_state.shootout.as_mut() else {
+            return;
+        };
+        state.record_kick(scored);
+        state.next_kick_at_ms = time_ms + SHOOTOUT_KICK_INTERVAL_MS;
+        if state.winner().is_some() {
+            self.finish_match();
+        }
+    }
+    
+    /// 골키퍼 이동 목표: 박스 안의 루즈볼은 직접 처리, 그 외에는 공-골문 라인 위
+    fn goalkeeper_target(&self, keeper: &Player) -> Vec2 {
+        let goal = defended_goal(&self.config.pitch, keeper.team_id, self.match_state.ends_swapped);
+        let ball = self.ball.position;
+        
+        let closest_teammate = self.players.iter()
+            .filter(|p| p.team_id == keeper.team_id)
+            .min_by(|a, b| a.position.distance(&ball).total_cmp(&b.position.distance(&ball)))
+            .map(|p| p.id);
+        let target = if self.ball.owner.is_none()
+            && self.pending_shot.is_none()
+            && in_penalty_area(&self.config.pitch, &goal, &ball)
+            && closest_teammate == Some(keeper.id)
+        {
+            ball
+        } else {
+            goalkeeper_position(&goal, &ball)
+        };
+        clamp_to_penalty_area(&self.config.pitch, &goal, target)
+    }
+    
+    /// 골문으로 향하는 슈팅이 골키퍼 라인을 지나는 순간 선방 시도
+    fn attempt_save(&mut self, previous_ball_position: Vec2) {
+        let Some(shot) = self.pending_shot else {
+            return;
+        };
+        let Some(keeper) = self.goalkeeper(1 - shot.team_id).filter(|p| p.is_goalkeeper()) else {
+            return;
+        };
+        
+        // 공이 골키퍼의 깊이(y)를 지나거나 골키퍼 바로 옆을 지나는 순간에 판정
+        let before = previous_ball_position.y - keeper.position.y;
+        let after = self.ball.position.y - keeper.position.y;
+        let crossed = before * after <= 0.0;
+        let path_distance = distance_to_segment(&keeper.position, &previous_ball_position, &self.ball.position);
+        if !crossed && path_distance >= POSSESSION_RANGE {
+            return;
+        }
+        self.pending_shot = None;
+        if !shot.on_target {
+            return;
+        }
+        let save_point = if crossed {
+            let t = if (before - after).abs() > 0.0001 { before / (before - after) } else { 0.0 };
+            let crossing_x = previous_ball_position.x + (self.ball.position.x - previous_ball_position.x) * t;
+            Vec2::new(crossing_x, keeper.position.y)
+        } else {
+            self.ball.position
+        };
+        let lateral = if crossed { (save_point.x - keeper.position.x).abs() } else { path_distance };
+        
+        // 반응 시간 이후에만 다이빙으로 범위를 넓힐 수 있음
+        let elapsed = self.match_state.time_ms.saturating_sub(shot.taken_at_ms) as f32 / 1000.0;
+        let reach = GK_REACH + GK_DIVE_SPEED * (elapsed - GK_REACTION_SECONDS).max(0.0);
+        let reach_ratio = lateral / reach;
+        let save_chance = ((0.9 - 0.4 * reach_ratio) * (0.8 + 0.4 * keeper.persona.confidence)).min(0.95);
+        let out_of_reach = lateral > reach || self.ball.height > GOALKEEPER_HANDLING_HEIGHT;
+        if out_of_reach || self.rng.gen::<f32>() >= save_chance {
+            // 선방에 실패한 골키퍼는 지나가는 공을 곧바로 잡을 수 없음
+            self.last_kick = Some((keeper.id, self.match_state.time_ms));
+            return;
+        }
+        
+        let ball_speed = self.ball.velocity.length();
+        let caught = ball_speed < GK_CATCH_SPEED && self.rng.gen::<f32>() < 0.8 * (1.0 - reach_ratio);
+        self.last_touch = Some(keeper.id);
+        
+        if caught {
+            // 캐치: 골키퍼가 공을 소유
+            self.ball.position = save_point;
+            self.ball.velocity = Vec2::new(0.0, 0.0);
+            self.ball.height = 0.0;
+            self.ball.vertical_velocity = 0.0;
+            self.ball.owner = Some(keeper.id);
+            self.possession_since_ms = self.match_state.time_ms;
+            for player in &mut self.players {
+                player.has_ball = player.id == keeper.id;
+                if player.has_ball {
+                    player.position = save_point;
+                }
+            }
+        } else {
+            // 펀칭: 공을 옆으로 쳐냄
+            let goal = defended_goal(&self.config.pitch, keeper.team_id, self.match_state.ends_swapped);
+            let side = if save_point.x < keeper.position.x { -1.0 } else { 1.0 };
+            self.ball.position = save_point;
+            self.ball.velocity = Vec2::new(
+                side * ball_speed * self.rng.gen_range(0.2..0.5),
+                goal.inward() * ball_speed * self.rng.gen_range(0.1..0.3),
+            );
+            self.last_kick = Some((keeper.id, self.match_state.time_ms));
+        }
+        
+        let mut keeper_at_save = keeper.clone();
+        keeper_at_save.position = save_point;
+        self.push_event(
+            EventType::Save,
+            &keeper_at_save,
+            EventPayload::Save {
+                shooter_id: shot.shooter_id.to_string(),
+                caught,
+            },
+            EventOutcome::Success,
+        );
+        if caught {
+            // 캐치는 공격 팀의 턴오버
+            if let Some(shooter) = self.find_player(shot.shooter_id) {
+                self.push_event(EventType::Turnover, &shooter, EventPayload::Empty, EventOutcome::Failure);
+            }
+        }
+    }
+    
+    /// 팀의 골키퍼 (지정된 골키퍼가 없으면 자기 골대에 가장 가까운 선수)
+    pub fn goalkeeper(&self, team_id: u8) -> Option<Player> {
+        let own_goal = defended_goal(&self.config.pitch, team_id, self.match_state.ends_swapped).center;
+        self.players.iter()
+            .filter(|p| p.team_id == team_id)
+            .find(|p| p.is_goalkeeper())
+            .or_else(|| {
+                self.players.iter()
+                    .filter(|p| p.team_id == team_id)
+                    .min_by(|a, b| a.position.distance(&own_goal).total_cmp(&b.position.distance(&own_goal)))
+            })
+            .cloned()
+    }
+    
+    /// 경기 종료: 공을 멈추고 더 이상 진행하지 않음
+    fn finish_match(&mut self) {
+        self.match_state.phase = MatchPhase::Finished;
+        self.pending_restart = None;
+        self.pending_pass = None;
+        self.ball.velocity = Vec2::new(0.0, 0.0);
+        self.ball.owner = None;
+        for player in &mut self.players {
+            player.has_ball = false;
+        }
+    }
+    
+    /// 팀의 마지막 터치 (아웃 판정용)
+    pub fn last_touch_team(&self) -> Option<u8> {
+        self.last_touch
+            .and_then(|id| self.players.iter().find(|p| p.id == id))
+            .map(|p| p.team_id)
+    }
+    
+    /// 아웃 오브 플레이: 스로인/골킥/코너킥 선언
+    fn award_restart(&mut self, exit: BoundaryExit) {
+        let last_team = self.last_touch_team().unwrap_or(0);
+        let exit_point = self.ball.position;
+        
+        let (kind, team_id, location) = match exit {
+            BoundaryExit::Touchline => {
+                let x = if exit_point.x < 0.0 { 0.0 } else { self.config.pitch.width };
+                let y = exit_point.y.clamp(0.0, self.config.pitch.length);
+                (SetPieceKind::ThrowIn, 1 - last_team, Vec2::new(x, y))
+            }
+            BoundaryExit::GoalLine { defending_team } if last_team == defending_team => {
+                // 수비 팀이 마지막 터치 → 공격 팀 코너킥
+                let goal = defended_goal(&self.config.pitch, defending_team, self.match_state.ends_swapped);
+                let x = if exit_point.x < goal.center.x { 0.0 } else { self.config.pitch.width };
+                (SetPieceKind::Corner, 1 - defending_team, Vec2::new(x, goal.center.y))
+            }
+            BoundaryExit::GoalLine { defending_team } => {
+                let goal = defended_goal(&self.config.pitch, defending_team, self.match_state.ends_swapped);
+                let y = if goal.center.y < self.config.pitch.length / 2.0 {
+                    self.config.pitch.goal_area_depth
+                } else {
+                    self.config.pitch.length - self.config.pitch.goal_area_depth
+                };
+                (SetPieceKind::GoalKick, defending_team, Vec2::new(goal.center.x, y))
+            }
+        };
+        
+        // 밖으로 나간 패스는 실패
+        if let Some(pass) = self.pending_pass.take() {
+            if let Some(mut passer) = self.find_player(pass.passer_id) {
+                passer.position = pass.origin;
+                self.push_event(
+                    EventType::Pass,
+                    &passer,
+                    EventPayload::Pass {
+                        target_player_id: pass.receiver_id.to_string(),
+                        distance: pass.distance,
+                        risk: pass.risk,
+                    },
+                    EventOutcome::Incomplete,
+                );
+            }
+        }
+        
+        self.set_up_restart(kind, team_id, location);
+    }
+    
+    /// 세트피스 준비: 키커 지정, 공 배치, 데드볼 상태 전환
+    fn set_up_restart(&mut self, kind: SetPieceKind, team_id: u8, location: Vec2) {
+        // 골킥은 골키퍼, 페널티킥은 전담 키커, 나머지는 세트피스 위치에 가장 가까운 선수가 키커
+        let team: Vec<&Player> = self.players.iter().filter(|p| p.team_id == team_id).collect();
+        let nearest = team.iter()
+            .min_by(|a, b| a.position.distance(&location).total_cmp(&b.position.distance(&location)))
+            .map(|p| (*p).clone());
+        let taker = match kind {
+            SetPieceKind::GoalKick => self.goalkeeper(team_id).filter(|p| p.is_goalkeeper()).or(nearest),
+            SetPieceKind::Penalty => {
+                let outfield: Vec<&Player> = team.iter().copied().filter(|p| !p.is_goalkeeper()).collect();
+                shootout::order_takers(&outfield).first().and_then(|id| self.find_player(*id)).or(nearest)
+            }
+            _ => nearest,
+        };
+        let Some(mut taker) = taker else {
+            return;
+        };
+        self.pending_shot = None;
+        self.pending_pass = None;
+        self.restart_kind = None;
+        
+        self.ball = Ball::new(location.x, location.y);
+        for player in &mut self.players {
+            player.has_ball = false;
+        }
+        self.last_kick = None;
+        self.pressing_players.clear();
+        self.match_state.phase = MatchPhase::Restart;
+        self.pending_restart = Some(PendingRestart {
+            kind,
+            team_id,
+            taker_id: taker.id,
+            location,
+            awarded_at_ms: self.match_state.time_ms,
+            resume_at_ms: self.match_state.time_ms + RESTART_DELAY_MS,
+        });
+        
+        taker.position = location;
+        self.push_event(
+            EventType::SetPiece,
+            &taker,
+            EventPayload::SetPiece { kind },
+            EventOutcome::Success,
+        );
+    }
+    
+    /// 세트피스 대기 중 선수 재배치 목표 (대기 중이 아니면 None)
+    fn restart_position(&self, player: &Player) -> Option<Vec2> {
+        let restart = self.pending_restart.as_ref()?;
+        if player.id == restart.taker_id {
+            return Some(restart.location);
+        }
+        
+        if restart.kind == SetPieceKind::Penalty {
+            // 수비 골키퍼는 골라인, 나머지는 페널티 박스 밖으로
+            let goal = defended_goal(&self.config.pitch, 1 - restart.team_id, self.match_state.ends_swapped);
+            if player.team_id != restart.team_id && player.is_goalkeeper() {
+                return Some(Vec2::new(goal.center.x, goal.center.y + goal.inward() * 0.5));
+            }
+            if in_penalty_area(&self.config.pitch, &goal, &player.position) {
+                let edge = goal.center.y + goal.inward() * (self.config.pitch.penalty_area_depth + 1.0);
+                return Some(Vec2::new(player.position.x, edge));
+            }
+        }
+        
+        // 상대 팀은 세트피스 지점에서 일정 거리 이상 물러남
+        let distance = player.position.distance(&restart.location);
+        if player.team_id != restart.team_id && distance < RESTART_DISTANCE {
+            let away = Vec2::new(
+                player.position.x - restart.location.x,
+                player.position.y - restart.location.y,
+            ).normalize();
+            let away = if away.length() > 0.0 {
+                away
+            } else {
+                Vec2::new(0.0, if restart.location.y < self.config.pitch.length / 2.0 { 1.0 } else { -1.0 })
+            };
+            let target = Vec2::new(
+                restart.location.x + away.x * RESTART_DISTANCE,
+                restart.location.y + away.y * RESTART_DISTANCE,
+            );
+            return Some(Vec2::new(
+                target.x.clamp(0.0, self.config.pitch.width),
+                target.y.clamp(0.0, self.config.pitch.length),
+            ));
+        }
+        None
+    }
+    
+    /// 데드볼 시간이 끝나면 키커에게 공을 주고 플레이 재개
+    fn resume_from_restart(&mut self, restart: PendingRestart, grid: &SpatialGrid) {
+        self.pending_restart = None;
+        self.match_state.phase = MatchPhase::Live;
+        self.ball = Ball::new(restart.location.x, restart.location.y);
+        self.ball.owner = Some(restart.taker_id);
+        for player in &mut self.players {
+            player.has_ball = player.id == restart.taker_id;
+            if player.has_ball {
+                player.position = restart.location;
+                player.velocity = Vec2::default();
+            }
+        }
+        self.possession_since_ms = self.match_state.time_ms;
+        self.restart_kind = Some(restart.kind);
+        // 직접 프리킥은 바로 슈팅할 수 있으므로 패스를 강제하지 않음
+        self.restart_taker = (restart.kind != SetPieceKind::DirectFreeKick).then_some(restart.taker_id);
+        self.record_possession_change(None, Some(restart.taker_id));
+        
+        if restart.kind == SetPieceKind::Penalty {
+            self.restart_taker = None;
+            let shot = BallAction {
+                action: UtilityAction::Shoot,
+                target: attacking_goal(&self.config.pitch, restart.team_id, self.match_state.ends_swapped),
+                receiver_id: None,
+            };
+            self.execute_ball_action(restart.taker_id, shot, grid, TICK_SECONDS);
+        }
+    }
+    
+    /// 센터 서클 킥오프 (실점한 팀이 공을 가짐)
+    fn restart_kickoff(&mut self, kicking_team: u8) {
+        let center = Vec2::new(self.config.pitch.width / 2.0, self.config.pitch.length / 2.0);
+        let half_line = self.config.pitch.length / 2.0;
+        let low_team = team_defending_low_end(self.match_state.ends_swapped);
+        
+        // 모든 선수를 자기 진영으로 이동
+        for player in &mut self.players {
+            player.has_ball = false;
+            player.velocity = Vec2::default();
+            player.position.y = if player.team_id == low_team {
+                player.position.y.min(half_line - 1.0)
+            } else {
+                player.position.y.max(half_line + 1.0)
+            };
+        }
+        
+        // 센터에 가장 가까운 선수가 킥오프
+        let taker_id = self.players.iter()
+            .filter(|p| p.team_id == kicking_team)
+            .min_by(|a, b| a.position.distance(&center).total_cmp(&b.position.distance(&center)))
+            .map(|p| p.id);
+        
+        self.ball = Ball::new(center.x, center.y);
+        self.ball.owner = taker_id;
+        if let Some(taker) = taker_id.and_then(|id| self.players.iter_mut().find(|p| p.id == id)) {
+            taker.position = center;
+            taker.has_ball = true;
+        }
+        
+        self.last_touch = taker_id;
+        self.last_kick = None;
+        self.pending_pass = None;
+        self.last_completed_pass = None;
+        self.pressing_players.clear();
+        self.pending_restart = None;
+        self.restart_taker = None;
+        self.restart_kind = None;
+        self.match_state.phase = MatchPhase::Live;
+        self.possession_since_ms = self.match_state.time_ms;
+        // 킥오프 직전(득점 후, 피리어드 시작)도 경기가 멈춘 때라 대기 중인 교체를 실행
+        self.apply_pending_substitutions();
+    }
+    
+    /// 볼 소유자 근처 수비수의 태클 시도
+    fn attempt_tackles(&mut self) {
+        let time_ms = self.match_state.time_ms;
+        self.tackle_cooldowns.retain(|(_, until_ms)| *until_ms > time_ms);
+        
+        let Some(carrier) = self.ball.owner.and_then(|id| self.find_player(id)) else {
+            return;
+        };
+        // 자기 박스 안에서 공을 잡은 골키퍼는 태클할 수 없음
+        let own_goal = defended_goal(&self.config.pitch, carrier.team_id, self.match_state.ends_swapped);
+        if carrier.is_goalkeeper() && in_penalty_area(&self.config.pitch, &own_goal, &carrier.position) {
+            return;
+        }
+        let tacklers: Vec<Player> = self.players.iter()
+            .filter(|p| p.team_id != carrier.team_id)
+            .filter(|p| p.position.distance(&carrier.position) < POSSESSION_RANGE)
+            .filter(|p| !self.tackle_cooldowns.iter().any(|(id, _)| *id == p.id))
+            .cloned()
+            .collect();
+        
+        for tackler in tacklers {
+            let successful = self.rng.gen::<f32>() < tackle_success_probability(&tackler, &carrier);
+            if self.rng.gen::<f32>() < foul_probability(&tackler, successful) {
+                self.commit_foul(&tackler, &carrier);
+                return;
+            }
+            if successful {
+                // 성공: 공을 빼앗음 (이벤트는 소유권 변화에서 기록)
+                // 공을 빼앗긴 선수는 곧바로 되받아 태클할 수 없음
+                self.ball.owner = Some(tackler.id);
+                self.ball.position = tackler.position;
+                self.tackle_cooldowns.push((carrier.id, time_ms + TACKLE_COOLDOWN_MS));
+                return;
+            }
+            self.tackle_cooldowns.push((tackler.id, time_ms + TACKLE_COOLDOWN_MS));
+            self.push_event(
+                EventType::Tackle,
+                &tackler,
+                EventPayload::Tackle {
+                    on_player_id: carrier.id.to_string(),
+                    successful: false,
+                },
+                EventOutcome::Failure,
+            );
+        }
+    }
+    
+    /// 반칙 처리: 반칙 이벤트, 카드, 위치에 따른 프리킥/페널티킥
+    fn commit_foul(&mut self, offender: &Player, victim: &Player) {
+        let location = victim.position;
+        let own_goal = defended_goal(&self.config.pitch, offender.team_id, self.match_state.ends_swapped);
+        let direct = offender.position.distance(&victim.position) < IMPEDING_DISTANCE;
+        
+        let (kind, restart_location) = if direct && in_penalty_area(&self.config.pitch, &own_goal, &location) {
+            let spot = Vec2::new(own_goal.center.x, own_goal.center.y + own_goal.inward() * self.config.pitch.penalty_spot_distance);
+            (SetPieceKind::Penalty, spot)
+        } else if direct {
+            (SetPieceKind::DirectFreeKick, location)
+        } else {
+            (SetPieceKind::IndirectFreeKick, indirect_free_kick_spot(&self.config.pitch, &own_goal, location))
+        };
+        
+        self.push_event(
+            EventType::Foul,
+            offender,
+            EventPayload::Foul {
+                on_player_id: victim.id.to_string(),
+                restart: kind,
+            },
+            EventOutcome::Failure,
+        );
+        
+        let (yellow, red) = card_probabilities(offender);
+        let roll = self.rng.gen::<f32>();
+        if roll < red {
+            self.show_card(offender, Card::Red);
+        } else if roll < red + yellow {
+            self.show_card(offender, Card::Yellow);
+        }
+        
+        self.set_up_restart(kind, victim.team_id, restart_location);
+    }
+    
+    /// 오프사이드 선언: 패스 무효, 수비 팀 간접 프리킥
+    fn call_offside(&mut self, pass: PendingPass, offender: Player, offside_at: Vec2) {
+        self.last_completed_pass = None;
+        if let Some(mut passer) = self.find_player(pass.passer_id) {
+            passer.position = pass.origin;
+            self.push_event(
+                EventType::Pass,
+                &passer,
+                EventPayload::Pass {
+                    target_player_id: pass.receiver_id.to_string(),
+                    distance: pass.distance,
+                    risk: pass.risk,
+                },
+                EventOutcome::Incomplete,
+            );
+        }
+        
+        let mut offender_at = offender.clone();
+        offender_at.position = offside_at;
+        self.push_event(
+            EventType::Offside,
+            &offender_at,
+            EventPayload::Offside {
+                passer_id: pass.passer_id.to_string(),
+            },
+            EventOutcome::Failure,
+        );
+        
+        let defending_team = 1 - offender.team_id;
+        let goal = defended_goal(&self.config.pitch, defending_team, self.match_state.ends_swapped);
+        self.set_up_restart(SetPieceKind::IndirectFreeKick, defending_team, indirect_free_kick_spot(&self.config.pitch, &goal, offside_at));
+    }
+    
+    /// 오프사이드 판정이 적용되는 패스인지 (스로인, 골킥, 코너킥에서 바로 받은 경우는 면제)
+    fn offside_applies(&self, restart_kind: Option<SetPieceKind>) -> bool {
+        self.config.rules.offside
+            && !matches!(
+                restart_kind,
+                Some(SetPieceKind::ThrowIn | SetPieceKind::GoalKick | SetPieceKind::Corner)
+            )
+    }
+    
+    /// 카드 기록 (두 번째 경고는 퇴장)
+    fn show_card(&mut self, player: &Player, card: Card) {
+        let second_yellow = card == Card::Yellow && self.bookings.contains(&player.id);
+        let sent_off = card == Card::Red || second_yellow;
+        if card == Card::Yellow && !second_yellow {
+            self.bookings.push(player.id);
+        }
+        self.push_event(
+            EventType::Card,
+            player,
+            EventPayload::Card { card, sent_off },
+            EventOutcome::Failure,
+        );
+        if sent_off {
+            self.send_off(player.id);
+        }
+    }
+    
+    /// 퇴장: 선수를 경기장에서 제거 (골키퍼가 퇴장당하면 가장 가까운 필드 플레이어가 장갑을 낌)
+    fn send_off(&mut self, player_id: u32) {
+        let Some(index) = self.players.iter().position(|p| p.id == player_id) else {
+            return;
+        };
+        let player = self.players.remove(index);
+        self.current_intents.retain(|intent| intent.player_id != player_id);
+        self.pressing_players.retain(|id| *id != player_id);
+        self.tackle_cooldowns.retain(|(id, _)| *id != player_id);
+        if self.ball.owner == Some(player_id) {
+            self.ball.owner = None;
+        }
+        if self.last_touch == Some(player_id) {
+            self.last_touch = None;
+        }
+        
+        if player.is_goalkeeper() {
+            let goal = defended_goal(&self.config.pitch, player.team_id, self.match_state.ends_swapped);
+            if let Some(replacement) = self.players.iter_mut()
+                .filter(|p| p.team_id == player.team_id)
+                .min_by(|a, b| a.position.distance(&goal.center).total_cmp(&b.position.distance(&goal.center)))
+            {
+                replacement.role = ROLE_GOALKEEPER.to_string();
+            }
+        }
+        self.swing_team_morale(player.team_id, morale::SENDING_OFF_SWING);
+        self.sent_off.push(player);
+    }
+    
+    /// 팀 전술 교체 (다음 스텝부터 새 포메이션 위치로 이동)
+    pub fn set_tactics(&mut self, team_id: u8, tactics: Tactics) {
+        self.tactics[team_id as usize] = tactics;
+    }
+    
+    /// 필드 플레이어의 포메이션 자리 번호 (팀 안에서 골키퍼를 제외한 순서)
+    fn formation_slot(&self, player: &Player) -> Option<usize> {
+        if player.is_goalkeeper() {
+            return None;
+        }
+        self.players.iter()
+            .filter(|p| p.team_id == player.team_id && !p.is_goalkeeper())
+            .position(|p| p.id == player.id)
+    }
+    
+    /// 선수의 전술 역할 (골키퍼는 None)
+    pub fn player_role(&self, player_id: u32) -> Option<Role> {
+        let player = self.players.iter().find(|p| p.id == player_id)?;
+        let slot = self.formation_slot(player)?;
+        self.tactics[player.team_id as usize].role(slot)
+    }
+    
+    /// 팀 관점의 현재 경기 국면
+    pub fn play_phase(&self, team_id: u8) -> PlayPhase {
+        if self.pending_restart.is_some() {
+            return PlayPhase::Contested;
+        }
+        match self.ball.owner.and_then(|id| self.players.iter().find(|p| p.id == id)) {
+            Some(owner) if owner.team_id == team_id => PlayPhase::InPossession,
+            Some(_) => PlayPhase::OutOfPossession,
+            None => PlayPhase::Contested,
+        }
+    }
+    
+    /// 포메이션, 공 위치, 국면으로 정해지는 선수의 기본 위치
+    fn home_position(&self, player: &Player) -> Vec2 {
+        let Some(slot) = self.formation_slot(player) else {
+            return player.position;
+        };
+        tactics::home_position(
+            &self.config.pitch,
+            &self.tactics[player.team_id as usize],
+            slot,
+            player.team_id == team_defending_low_end(self.match_state.ends_swapped),
+            &self.ball.position,
+            self.play_phase(player.team_id),
+        )
+    }
+    
+    /// 수비 시 상대 볼 소유자를 막아서는 위치와 스프린트 여부
+    ///
+    /// 압박 역할은 자기 자리 근처의 소유자에게 달려가 골문 쪽 앞을 막고,
+    /// 나머지는 자리가 소유자와 겹칠 때만 일정 거리를 두고 골문 쪽에 섬
+    fn closing_down_target(&self, player: &Player, home: Vec2) -> Option<(Vec2, bool)> {
+        if self.play_phase(player.team_id) != PlayPhase::OutOfPossession {
+            return None;
+        }
+        let carrier = self.ball.owner.and_then(|id| self.players.iter().find(|p| p.id == id))?;
+        let pressing = self.player_role(player.id).is_some_and(|role| role.behavior == Behavior::Press)
+            && carrier.position.distance(&home) < PRESS_ROLE_RANGE;
+        let distance = if pressing {
+            PRESS_ROLE_DISTANCE
+        } else if carrier.position.distance(&home) < JOCKEY_DISTANCE {
+            JOCKEY_DISTANCE
+        } else {
+            return None;
+        };
+        Some((self.goal_side_of(player.team_id, carrier.position, distance), pressing))
+    }
+    
+    /// 위치에서 팀의 자기 골문 쪽으로 일정 거리 떨어진 지점
+    fn goal_side_of(&self, team_id: u8, position: Vec2, distance: f32) -> Vec2 {
+        let own_goal = defended_goal(&self.config.pitch, team_id, self.match_state.ends_swapped).center;
+        let goal_side = Vec2::new(own_goal.x - position.x, own_goal.y - position.y).normalize();
+        Vec2::new(position.x + goal_side.x * distance, position.y + goal_side.y * distance)
+    }
+    
+    /// 루즈볼을 쫓을 선수 (팀별로 가장 가까운 필드 플레이어 한 명)
+    fn loose_ball_chasers(&self) -> Vec<u32> {
+        if self.ball.owner.is_some() || self.pending_restart.is_some() {
+            return Vec::new();
+        }
+        (0..2u8)
+            .filter_map(|team_id| {
+                self.players.iter()
+                    .filter(|p| p.team_id == team_id && !p.is_goalkeeper())
+                    .min_by(|a, b| {
+                        a.position.distance(&self.ball.position)
+                            .total_cmp(&b.position.distance(&self.ball.position))
+                    })
+                    .map(|p| p.id)
+            })
+            .collect()
+    }
+    
+    /// 새 이벤트로 팀 전술 트리거를 평가해 단기 의도 발행
+    ///
+    /// 공을 잃으면 역압박, 상대 진영 측면에서 패스를 받으면 같은 쪽 수비수의 오버랩이
+    /// 다음 LLM 호출을 기다리지 않고 바로 시작됨
+    fn evaluate_triggers(&mut self) {
+        let new_events = self.events[self.trigger_event_cursor..].to_vec();
+        self.trigger_event_cursor = self.events.len();
+        if self.pending_restart.is_some() {
+            return;
+        }
+        for event in &new_events {
+            let Ok(team_id) = event.team_id.parse::<u8>() else {
+                continue;
+            };
+            let Some((trigger, location)) = self.event_trigger(event, team_id) else {
+                continue;
+            };
+            if !self.tactics[team_id as usize].triggers.contains(&trigger)
+                || self.trigger_cooling_down(team_id, trigger)
+            {
+                continue;
+            }
+            let intents = match trigger {
+                Trigger::PressOnLoss => self.press_on_loss_intents(team_id, location),
+                Trigger::OverlapLeft | Trigger::OverlapRight => self.overlap_intents(team_id, location, trigger),
+            };
+            if intents.is_empty() {
+                continue;
+            }
+            self.trigger_log.push(TriggerFiring {
+                t_ms: self.match_state.time_ms,
+                team_id,
+                trigger,
+                event_id: event.id.clone(),
+                player_ids: intents.iter().map(|intent| intent.player_id).collect(),
+            });
+            self.update_intents(intents);
+        }
+    }
+    
+    /// 팀의 같은 트리거가 최근에 발동했는지
+    fn trigger_cooling_down(&self, team_id: u8, trigger: Trigger) -> bool {
+        self.trigger_log.iter()
+            .rev()
+            .find(|firing| firing.team_id == team_id && firing.trigger == trigger)
+            .is_some_and(|firing| self.match_state.time_ms < firing.t_ms + triggers::TRIGGER_COOLDOWN_MS)
+    }
+    
+    /// 이벤트가 해당하는 트리거와 기준 위치
+    fn event_trigger(&self, event: &MatchEvent, team_id: u8) -> Option<(Trigger, Vec2)> {
+        match (&event.event_type, &event.payload) {
+            (EventType::Turnover, _) => Some((Trigger::PressOnLoss, event.location)),
+            (EventType::Pass, EventPayload::Pass { target_player_id, .. })
+                if matches!(event.outcome, EventOutcome::Complete) =>
+            {
+                let receiver = self.find_player(target_player_id.parse().ok()?)?;
+                let defends_low_end = team_id == team_defending_low_end(self.match_state.ends_swapped);
+                let trigger = triggers::overlap_trigger(&self.config.pitch, &receiver.position, defends_low_end)?;
+                Some((trigger, receiver.position))
+            }
+            _ => None,
+        }
+    }
+    
+    /// 트리거가 발행하는 단기 의도
+    fn trigger_intent(&self, player_id: u32, action: Action, duration_ms: u64) -> Intent {
+        Intent {
+            duration_ms: Some(duration_ms),
+            ..Intent::new(player_id, IntentStatus::New, Some(action), self.match_state.time_ms)
+        }
+    }
+    
+    /// 역압박: 공을 잃은 지점에 가까운 선수들이 바로 되찾으러 감
+    ///
+    /// 가장 가까운 선수는 공 앞 골문 쪽을 막아서고, 나머지는 공을 잃은 지점으로 달려감
+    fn press_on_loss_intents(&self, team_id: u8, location: Vec2) -> Vec<Intent> {
+        let mut pressers: Vec<&Player> = self.players.iter()
+            .filter(|p| p.team_id == team_id && !p.is_goalkeeper())
+            .filter(|p| p.position.distance(&location) < triggers::PRESS_ON_LOSS_RANGE)
+            .collect();
+        pressers.sort_by(|a, b| {
+            a.position.distance(&location)
+                .total_cmp(&b.position.distance(&location))
+        });
+        pressers.iter()
+            .take(triggers::PRESS_ON_LOSS_PLAYERS)
+            .enumerate()
+            .map(|(rank, presser)| {
+                let target = if rank == 0 { self.goal_side_of(team_id, self.ball.position, PRESS_ROLE_DISTANCE) } else { location };
+                let action = Action::Press { target: decision_plugin::Vec2::new(target.x, target.y) };
+                self.trigger_intent(presser.id, action, triggers::PRESS_ON_LOSS_MS)
+            })
+            .collect()
+    }
+    
+    /// 오버랩: 공을 받은 쪽 측면의 가장 바깥 수비수가 공보다 앞으로 침투
+    fn overlap_intents(&self, team_id: u8, location: Vec2, trigger: Trigger) -> Vec<Intent> {
+        let slots = self.tactics[team_id as usize].formation.slots();
+        if slots.is_empty() {
+            return Vec::new();
+        }
+        let left = trigger == Trigger::OverlapLeft;
+        let runner = self.players.iter()
+            .filter(|p| p.team_id == team_id && !p.has_ball)
+            .filter_map(|p| {
+                let (zone, base) = slots[self.formation_slot(p)? % slots.len()];
+                (zone == Zone::Defense && (base.x < 0.5) == left).then_some((p, base.x))
+            })
+            .min_by(|(_, a), (_, b)| {
+                if left { a.total_cmp(b) } else { b.total_cmp(a) }
+            });
+        let Some((runner, _)) = runner else {
+            return Vec::new();
+        };
+        let defends_low_end = team_id == team_defending_low_end(self.match_state.ends_swapped);
+        let target = triggers::overlap_target(&self.config.pitch, &location, trigger, defends_low_end);
+        let action = Action::AttackSpace { target: decision_plugin::Vec2::new(target.x, target.y) };
+        vec![self.trigger_intent(runner.id, action, triggers::OVERLAP_MS)]
+    }
+    
+    /// 경기장에 남아 있는 팀 선수 수
+    pub fn players_on_pitch(&self, team_id: u8) -> usize {
+        self.players.iter().filter(|p| p.team_id == team_id).count()
+    }
+    
+    /// 팀의 누적 기대 득점 (승부차기 제외)
+    pub fn team_xg(&self, team_id: u8) -> f32 {
+        let team = team_id.to_string();
+        self.events.iter()
+            .filter(|event| event.team_id == team && event.period != format!("{:?}", Period::Penalties))
+            .filter_map(|event| match event.payload {
+                EventPayload::Shot { xg, .. } => Some(xg),
+                _ => None,
+            })
+            .sum()
+    }
+    
+    /// 팀의 남은 교체 횟수 (대기 중인 교체 포함)
+    pub fn substitutions_remaining(&self, team_id: u8) -> u8 {
+        let pending = self.pending_substitutions.iter().filter(|sub| sub.team_id == team_id).count() as u8;
+        self.config.rules.max_substitutions
+            .saturating_sub(self.substitutions_made[team_id as usize])
+            .saturating_sub(pending)
+    }
+    
+    /// 교체 요청 (감독 확정)
+    ///
+    /// 인플레이 중에는 대기했다가 다음 데드볼에 실행됨
+    pub fn request_substitution(&mut self, team_id: u8, player_out: u32, player_in: u32) -> Result<(), SubstitutionError> {
+        if matches!(self.match_state.phase, MatchPhase::Shootout | MatchPhase::Finished) {
+            return Err(SubstitutionError::MatchNotInPlay);
+        }
+        if !self.players.iter().any(|p| p.id == player_out && p.team_id == team_id) {
+            return Err(SubstitutionError::PlayerNotOnPitch(player_out));
+        }
+        if !self.bench[team_id as usize].iter().any(|p| p.id == player_in) {
+            return Err(SubstitutionError::PlayerNotOnBench(player_in));
+        }
+        for id in [player_out, player_in] {
+            if self.pending_substitutions.iter().any(|sub| sub.player_out == id || sub.player_in == id) {
+                return Err(SubstitutionError::AlreadyRequested(id));
+            }
+        }
+        if self.substitutions_remaining(team_id) == 0 {
+            return Err(SubstitutionError::LimitReached);
+        }
+        self.pending_substitutions.push(Substitution { team_id, player_out, player_in });
+        Ok(())
+    }
+    
+    /// 데드볼 중 대기 중인 교체 실행
+    ///
+    /// 들어오는 선수는 나가는 선수의 자리(포메이션 자리, 위치, 역할)와 의도,
+    /// 세트피스 키커 지정을 그대로 이어받음
+    fn apply_pending_substitutions(&mut self) {
+        for sub in std::mem::take(&mut self.pending_substitutions) {
+            let team = sub.team_id as usize;
+            // 요청 이후 퇴장당한 선수는 교체할 수 없음
+            let Some(index) = self.players.iter().position(|p| p.id == sub.player_out) else {
+                continue;
+            };
+            let Some(bench_index) = self.bench[team].iter().position(|p| p.id == sub.player_in) else {
+                continue;
+            };
+            let mut incoming = self.bench[team].remove(bench_index);
+            let outgoing = &self.players[index];
+            incoming.position = outgoing.position;
+            incoming.velocity = Vec2::default();
+            incoming.role = outgoing.role.clone();
+            incoming.has_ball = outgoing.has_ball;
+            if incoming.is_goalkeeper() {
+                set_goalkeeper_persona(&mut incoming.persona);
+            }
+            let outgoing = std::mem::replace(&mut self.players[index], incoming.clone());
+            
+            for intent in self.current_intents.iter_mut().filter(|intent| intent.player_id == sub.player_out) {
+                intent.player_id = sub.player_in;
+            }
+            if let Some(restart) = self.pending_restart.as_mut().filter(|restart| restart.taker_id == sub.player_out) {
+                restart.taker_id = sub.player_in;
+            }
+            if self.ball.owner == Some(sub.player_out) {
+                self.ball.owner = Some(sub.player_in);
+            }
+            if self.restart_taker == Some(sub.player_out) {
+                self.restart_taker = Some(sub.player_in);
+            }
+            self.pressing_players.retain(|id| *id != sub.player_out);
+            
+            self.substitutions_made[team] += 1;
+            self.push_event(
+                EventType::Substitution,
+                &incoming,
+                EventPayload::Substitution {
+                    replaced_player_id: sub.player_out.to_string(),
+                },
+                EventOutcome::Success,
+            );
+            self.substituted.push(outgoing);
+        }
+    }
+    
+    /// 머리 높이로 날아온 공을 가장 가까운 선수가 헤더로 처리
+    ///
+    /// 자기 진영에서는 골문 반대쪽으로 걷어내고, 상대 진영에서는 앞쪽 동료에게 떨궈 줌.
+    /// 헤더한 선수가 공을 따낸 것으로 보고 진행 중이던 패스의 결과를 기록하며, 헤더했으면 true
+    fn attempt_header(&mut self, grid: &SpatialGrid) -> bool {
+        if self.ball.height <= CONTROL_HEIGHT || self.ball.height > HEADER_HEIGHT {
+            return false;
+        }
+        let excluded = self.last_kick
+            .filter(|(_, kicked_at)| self.match_state.time_ms < kicked_at + KICK_LOCKOUT_MS)
+            .map(|(kicker_id, _)| kicker_id);
+        let Some(header) = grid.within(self.ball.position, POSSESSION_RANGE)
+            .map(|(index, _)| &self.players[index])
+            .filter(|p| excluded != Some(p.id))
+            .map(|p| (p, p.position.distance(&self.ball.position)))
+            .filter(|(_, distance)| *distance < POSSESSION_RANGE)
+            .min_by(|a, b| a.1.total_cmp(&b.1).then(a.0.id.cmp(&b.0.id)))
+            .map(|(p, _)| p.clone())
+        else {
+            return false;
+        };
+        self.record_possession_change(None, Some(header.id));
+        if self.pending_restart.is_some() {
+            // 오프사이드 위치에서 헤더
+            return true;
+        }
+        
+        let pitch = &self.config.pitch;
+        let own_goal = defended_goal(pitch, header.team_id, self.match_state.ends_swapped);
+        let depth = |position: &Vec2| (position.y - own_goal.center.y).abs();
+        let in_own_half = depth(&header.position) < pitch.length / 2.0;
+        let mate = self.players.iter()
+            .filter(|p| !in_own_half && p.team_id == header.team_id && p.id != header.id)
+            .filter(|p| depth(&p.position) > depth(&header.position))
+            .map(|p| (p, p.position.distance(&header.position)))
+            .filter(|(_, distance)| *distance < HEADER_RANGE)
+            .min_by(|a, b| a.1.total_cmp(&b.1).then(a.0.id.cmp(&b.0.id)))
+            .map(|(p, distance)| (p.clone(), distance));
+        let target = match &mate {
+            Some((mate, _)) => mate.position,
+            None => {
+                // 골문 반대쪽으로 걷어냄 (좌우로 흩어짐)
+                let direction = Vec2::new(self.rng.gen_range(-0.6..0.6), own_goal.inward()).normalize();
+                Vec2::new(
+                    self.ball.position.x + direction.x * HEADER_RANGE,
+                    self.ball.position.y + direction.y * HEADER_RANGE,
+                )
+            }
+        };
+        kick_ball(&mut self.ball, target, HEADER_SPEED);
+        
+        let offside = self.offside_applies(None);
+        let defenders: Vec<Vec2> = self.players.iter()
+            .filter(|p| p.team_id != header.team_id)
+            .map(|p| p.position)
+            .collect();
+        let attacking = defended_goal(&self.config.pitch, 1 - header.team_id, self.match_state.ends_swapped);
+        self.pending_pass = mate.map(|(mate, distance)| PendingPass {
+            passer_id: header.id,
+            receiver_id: mate.id,
+            team_id: header.team_id,
+            origin: header.position,
+            distance,
+            risk: 0.0,
+            offside_at: (offside && is_offside_position(&self.config.pitch, &mate.position, &header.position, &defenders, &attacking))
+                .then_some(mate.position),
+        });
+        self.last_kick = Some((header.id, self.match_state.time_ms));
+        self.interception_attempts.clear();
+        true
+    }
+    
+    /// 패스 경로 근처의 수비수가 공을 끊어내려 시도 (수비수당 패스 한 번)
+    ///
+    /// 성공하면 true를 반환하며, 인터셉트 이벤트는 소유권 변화에서 기록
+    fn attempt_interceptions(&mut self, previous_ball_position: Vec2) -> bool {
+        let Some(pass) = self.pending_pass else {
+            return false;
+        };
+        // 머리 위로 넘어가는 로빙 패스는 끊을 수 없음
+        if self.ball.height > CONTROL_HEIGHT {
+            return false;
+        }
+        let ball_speed = self.ball.velocity.length();
+        let mut candidates: Vec<(Player, f32)> = self.players.iter()
+            .filter(|p| p.team_id != pass.team_id)
+            .filter(|p| !self.interception_attempts.contains(&p.id))
+            .map(|p| (p.clone(), distance_to_segment(&p.position, &previous_ball_position, &self.ball.position)))
+            .filter(|(_, distance)| *distance < INTERCEPTION_RANGE)
+            .collect();
+        // 경로에 가까운 수비수부터 시도
+        candidates.sort_by(|a, b| a.1.total_cmp(&b.1).then(a.0.id.cmp(&b.0.id)));
+        
+        for (defender, distance) in candidates {
+            self.interception_attempts.push(defender.id);
+            if self.rng.gen::<f32>() >= interception_probability(&defender, distance, ball_speed) {
+                continue;
+            }
+            self.ball.owner = Some(defender.id);
+            self.ball.position = defender.position;
+            self.ball.velocity = Vec2::new(0.0, 0.0);
+            self.ball.height = 0.0;
+            self.ball.vertical_velocity = 0.0;
+            self.possession_since_ms = self.match_state.time_ms;
+            for player in &mut self.players {
+                player.has_ball = player.id == defender.id;
+            }
+            return true;
+        }
+        false
+    }
+    
+    /// 볼 소유자에게 새로 접근한 수비수의 압박 기록
+    fn record_presses(&mut self) {
+        let Some(carrier) = self.ball.owner.and_then(|id| self.find_player(id)) else {
+            self.pressing_players.clear();
+            return;
+        };
+        let pressers: Vec<Player> = self.players.iter()
+            .filter(|p| p.team_id != carrier.team_id)
+            .filter(|p| p.position.distance(&carrier.position) < PRESS_RANGE)
+            .cloned()
+            .collect();
+        
+        for presser in &pressers {
+            if !self.pressing_players.contains(&presser.id) {
+                self.push_event(EventType::Press, presser, EventPayload::Empty, EventOutcome::Success);
+            }
+        }
+        self.pressing_players = pressers.iter().map(|p| p.id).collect();
+    }
+    
+    /// 볼 소유자의 다음 행동 결정 (유틸리티 AI)
+    fn choose_ball_action(&self, carrier_id: u32, grid: &SpatialGrid) -> Option<BallAction> {
+        let carrier = self.players.iter().find(|p| p.id == carrier_id)?;
+        let persona = &carrier.persona;
+        let goal = attacking_goal(&self.config.pitch, carrier.team_id, self.match_state.ends_swapped);
+        let hold = BallAction {
+            action: UtilityAction::Hold,
+            // 골키퍼는 드리블하지 않고 제자리에서 공을 보유
+            target: if carrier.is_goalkeeper() { carrier.position } else { goal },
+            receiver_id: None,
+        };
+        
+        // 골키퍼는 잠시 공을 잡고 있다가 배급
+        let held_ms = self.match_state.time_ms.saturating_sub(self.possession_since_ms);
+        if carrier.is_goalkeeper() && held_ms < GK_HOLD_MS {
+            return Some(hold);
+        }
+        
+        // FindPassOption 의도가 있으면 첫 터치 뒤 보유 시간과 관계없이 바로 패스 탐색
+        // 세트피스 키커와 골키퍼도 드리블 없이 바로 패스
+        let looking_for_pass = self.restart_taker == Some(carrier_id)
+            || carrier.is_goalkeeper()
+            || (held_ms >= FIRST_TOUCH_MS && self.current_intents.iter().any(|intent| {
+                intent.player_id == carrier_id
+                    && matches!(intent.action, Some(Action::FindPassOption))
+            }));
+        
+        // 인내심이 높을수록 공을 오래 보유
+        let min_hold_ms = 300 + (persona.patience * 700.0) as u64;
+        if !looking_for_pass && held_ms < min_hold_ms {
+            return Some(hold);
+        }
+        
+        let teammates: Vec<Player> = self.players.iter()
+            .filter(|p| p.team_id == carrier.team_id && p.id != carrier.id)
+            .cloned()
+            .collect();
+        let opponents = grid.team(1 - carrier.team_id);
+        
+        // 가장 좋은 패스 대상 선택
+        let best_pass = teammates.iter()
+            .filter(|mate| carrier.position.distance(&mate.position) >= MIN_PASS_DISTANCE)
+            .map(|mate| {
+                let utility = pass_utility(carrier, &mate.position, &goal, &teammates, &self.pitch_control, persona);
+                (mate, utility)
+            })
+            .max_by(|a, b| a.1.total_cmp(&b.1));
+        
+        let mut scores = Vec::new();
+        if !looking_for_pass {
+            // 보유 유틸리티는 보유 시간이 길어질수록 감소
+            let hold_utility = 0.4 * persona.patience * (1.0 - held_ms as f32 / 3000.0).max(0.0);
+            scores.push(ActionScore { action: UtilityAction::Hold, utility: hold_utility });
+            scores.push(ActionScore {
+                action: UtilityAction::Shoot,
+                utility: shoot_utility(
+                    carrier,
+                    &defended_goal(&self.config.pitch, 1 - carrier.team_id, self.match_state.ends_swapped),
+                    opponents,
+                    persona,
+                ),
+            });
+        }
+        if let Some((mate, utility)) = best_pass {
+            let action = if carrier.position.distance(&mate.position) > persona.vision_range * 0.7 {
+                UtilityAction::PassRisk
+            } else {
+                UtilityAction::PassSafe
+            };
+            scores.push(ActionScore { action, utility });
+        }
+        scores.retain(|score| score.utility > 0.0);
+        
+        match select_best_action(&scores) {
+            Some(UtilityAction::Shoot) => Some(BallAction {
+                action: UtilityAction::Shoot,
+                target: goal,
+                receiver_id: None,
+            }),
+            Some(action @ (UtilityAction::PassSafe | UtilityAction::PassRisk)) => {
+                best_pass.map(|(mate, _)| BallAction {
+                    action,
+                    target: mate.position,
+                    receiver_id: Some(mate.id),
+                })
+            }
+            // 마땅한 패스가 없어도 골키퍼는 가장 나은 동료에게 길게 차서 걷어냄
+            _ if carrier.is_goalkeeper() => Some(best_pass.map_or(hold, |(mate, _)| BallAction {
+                action: UtilityAction::PassRisk,
+                target: mate.position,
+                receiver_id: Some(mate.id),
+            })),
+            _ => Some(hold),
+        }
+    }
+    
+    /// 볼 소유자 행동 실행
+    fn execute_ball_action(&mut self, carrier_id: u32, ball_action: BallAction, grid: &SpatialGrid, delta_time: f32) {
+        let time_ms = self.match_state.time_ms;
+        let Some(carrier) = self.find_player(carrier_id) else {
+            return;
+        };
+        
+        match ball_action.action {
+            UtilityAction::PassSafe | UtilityAction::PassRisk => {
+                let defenders: Vec<Vec2> = self.players.iter()
+                    .filter(|p| p.team_id != carrier.team_id)
+                    .map(|p| p.position)
+                    .collect();
+                let distance = carrier.position.distance(&ball_action.target);
+                let risk = calculate_blocking_risk(&carrier.position, &ball_action.target, grid.team(1 - carrier.team_id));
+                // 패스 정확도: 거리가 멀고 자신감이 낮을수록 오차가 커짐 (골키퍼 스로는 더 정확)
+                let throw = carrier.is_goalkeeper() && distance <= GK_THROW_RANGE;
+                let accuracy = if throw { 0.5 } else { 1.0 };
+                let error_radius = distance * 0.08 * (1.5 - carrier.persona.confidence) * accuracy
+                    / performance_factor(carrier.stamina);
+                let error_angle = self.rng.gen_range(0.0..std::f32::consts::TAU);
+                let error = self.rng.gen::<f32>() * error_radius;
+                let aimed = Vec2::new(
+                    ball_action.target.x + error_angle.cos() * error,
+                    ball_action.target.y + error_angle.sin() * error,
+                );
+                // 골키퍼 킥, 측면 크로스, 막힌 패스 길은 수비수 머리 위로 띄움
+                let attacking = defended_goal(&self.config.pitch, 1 - carrier.team_id, self.match_state.ends_swapped);
+                let cross = in_penalty_area(&self.config.pitch, &attacking, &ball_action.target)
+                    && (carrier.position.x - attacking.center.x).abs() > self.config.pitch.penalty_area_width / 2.0;
+                let lofted = (carrier.is_goalkeeper() && !throw)
+                    || cross
+                    || (distance >= LOFT_MIN_DISTANCE && risk > LOFT_BLOCKING_RISK);
+                if lofted {
+                    let (horizontal, vertical) = lofted_pass_velocity(carrier.position.distance(&aimed));
+                    loft_ball(&mut self.ball, aimed, horizontal, vertical);
+                } else {
+                    kick_ball(&mut self.ball, aimed, pass_speed_for_distance(distance));
+                }
+                let restart_kind = self.restart_kind.take();
+                let offside_at = ball_action.receiver_id
+                    .filter(|_| self.offside_applies(restart_kind))
+                    .and_then(|receiver_id| self.find_player(receiver_id))
+                    .filter(|receiver| {
+                        let goal = defended_goal(&self.config.pitch, 1 - carrier.team_id, self.match_state.ends_swapped);
+                        is_offside_position(&self.config.pitch, &receiver.position, &carrier.position, &defenders, &goal)
+                    })
+                    .map(|receiver| receiver.position);
+                self.pending_pass = ball_action.receiver_id.map(|receiver_id| PendingPass {
+                    passer_id: carrier_id,
+                    receiver_id,
+                    team_id: carrier.team_id,
+                    origin: carrier.position,
+                    distance,
+                    risk,
+                    offside_at,
+                });
+                self.last_kick = Some((carrier_id, time_ms));
+                self.interception_attempts.clear();
+                self.restart_taker = None;
+            }
+            UtilityAction::Shoot => {
+                let shot_type = match self.restart_kind.take() {
+                    Some(SetPieceKind::Penalty) => ShotType::Penalty,
+                    Some(SetPieceKind::DirectFreeKick) => ShotType::DirectFreeKick,
+                    _ => ShotType::Foot,
+                };
+                // 슈팅 방향: 거리가 멀고 자신감이 낮을수록 골문 중심에서 벗어남
+                let goal = defended_goal(&self.config.pitch, 1 - carrier.team_id, self.match_state.ends_swapped);
+                let distance = carrier.position.distance(&goal.center);
+                let spread = goal.width / 2.0 * (0.5 + distance / 20.0) * (1.5 - carrier.persona.confidence)
+                    / performance_factor(carrier.stamina);
+                let aimed = Vec2::new(
+                    goal.center.x + self.rng.gen_range(-1.0..1.0) * spread,
+                    goal.center.y,
+                );
+                let on_target = goal.is_between_posts(aimed.x);
+                kick_ball(&mut self.ball, aimed, SHOT_SPEED);
+                self.pending_pass = None;
+                self.last_kick = Some((carrier_id, time_ms));
+                self.pending_shot = Some(PendingShot {
+                    shooter_id: carrier_id,
+                    team_id: carrier.team_id,
+                    taken_at_ms: time_ms,
+                    on_target,
+                });
+                
+                let angle = shot_angle(&carrier.position, &goal.center);
+                let xg = expected_goals(&ShotSituation {
+                    position: carrier.position,
+                    velocity: carrier.velocity,
+                    pressure: calculate_defensive_pressure(&carrier.position, grid.team(1 - carrier.team_id)),
+                    shot_type,
+                }, &goal);
+                self.push_event(
+                    EventType::Shot,
+                    &carrier,
+                    EventPayload::Shot {
+                        distance,
+                        angle,
+                        on_target,
+                        xg,
+                    },
+                    if on_target { EventOutcome::Success } else { EventOutcome::Failure },
+                );
+            }
+            _ => {
+                // 드리블: 일반 이동 속도보다 느리게 골대 방향으로 전진
+                let dribble_speed = DRIBBLE_SPEED * carrier.persona.work_rate * performance_factor(carrier.stamina);
+                let (new_pos, new_velocity) = steer_towards(
+                    carrier.position,
+                    carrier.velocity,
+                    ball_action.target,
+                    &movement_limits(&carrier, dribble_speed),
+                    delta_time,
+                );
+                if let Some(player) = self.players.iter_mut().find(|p| p.id == carrier_id) {
+                    player.position = new_pos;
+                    player.velocity = new_velocity;
+                }
+                self.ball.position = new_pos;
+                return;
+            }
+        }
+        
+        if let Some(player) = self.players.iter_mut().find(|p| p.id == carrier_id) {
+            player.has_ball = false;
+        }
+    }
+    
+    /// 홈 팀 플레이어들
+    pub fn home_players(&self) -> Vec<&Player> {
+        self.players.iter().filter(|p| p.team_id == 0).collect()
+    }
+    
+    /// 어웨이 팀 플레이어들
+    pub fn away_players(&self) -> Vec<&Player> {
+        self.players.iter().filter(|p| p.team_id == 1).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    /// 인플레이 상태에서 선수에게 공을 줌
+    fn give_ball(world: &mut GameWorld, player_id: u32, position: Vec2) {
+        world.pending_restart = None;
+        world.match_state.phase = MatchPhase::Live;
+        world.ball = Ball::new(position.x, position.y);
+        world.ball.owner = Some(player_id);
+        world.last_touch = Some(player_id);
+        for player in &mut world.players {
+            player.has_ball = player.id == player_id;
+            if player.has_ball {
+                player.position = position;
+                player.velocity = Vec2::default();
+            }
+        }
+    }
+    
+    /// 현재 선수 위치 격자로 볼 액션 실행
+    fn execute(world: &mut GameWorld, carrier_id: u32, ball_action: BallAction) {
+        let grid = SpatialGrid::from_players(&world.config.pitch, &world.players);
+        world.execute_ball_action(carrier_id, ball_action, &grid, TICK_SECONDS);
+    }
+    
+    fn attempt_header(world: &mut GameWorld) -> bool {
+        let grid = SpatialGrid::from_players(&world.config.pitch, &world.players);
+        world.attempt_header(&grid)
+    }
+    
+    fn outfield(world: &GameWorld, team_id: u8) -> Vec<u32> {
+        world.players.iter()
+            .filter(|p| p.team_id == team_id && !p.is_goalkeeper())
+            .map(|p| p.id)
+            .collect()
+    }
+    
+    #[test]
+    fn carrier_executes_pass_shot_and_dribble() {
+        let mut world = GameWorld::new_5v5();
+        let (carrier, mate) = (1, 2);
+        
+        // 패스: 공을 리시버 쪽으로 차고 진행 중인 패스로 기록
+        give_ball(&mut world, carrier, Vec2::new(16.0, 20.0));
+        let receiver = world.players.iter().find(|p| p.id == mate).unwrap().position;
+        let pass = BallAction { action: UtilityAction::PassSafe, target: receiver, receiver_id: Some(mate) };
+        execute(&mut world, carrier, pass);
+        let pending = world.pending_pass.expect("pass should be in flight");
+        assert_eq!((pending.passer_id, pending.receiver_id, pending.team_id), (carrier, mate, 0));
+        assert_eq!(world.ball.owner, None);
+        assert!(world.ball.velocity.length() > 0.0);
+        assert_eq!(world.last_kick, Some((carrier, world.match_state.time_ms)));
+        assert!(!world.players.iter().any(|p| p.has_ball));
+        
+        // 슈팅: 상대 골문으로 차고 xG가 담긴 슈팅 이벤트 기록
+        give_ball(&mut world, carrier, Vec2::new(16.0, 40.0));
+        let goal = attacking_goal(&world.config.pitch, 0, false);
+        let shot = BallAction { action: UtilityAction::Shoot, target: goal, receiver_id: None };
+        execute(&mut world, carrier, shot);
+        assert!(world.pending_pass.is_none());
+        let pending = world.pending_shot.expect("shot should be in flight");
+        assert_eq!(pending.shooter_id, carrier);
+        assert!(world.ball.velocity.y > 0.0);
+        let event = world.events.last().unwrap();
+        assert!(matches!(event.event_type, EventType::Shot));
+        let EventPayload::Shot { on_target, xg, .. } = event.payload else {
+            panic!("shot payload expected");
+        };
+        assert_eq!(on_target, pending.on_target);
+        assert!(xg > 0.0 && xg < 1.0);
+        
+        // 드리블: 공을 가진 채 목표 쪽으로 이동
+        give_ball(&mut world, carrier, Vec2::new(16.0, 20.0));
+        let events = world.events.len();
+        let dribble = BallAction { action: UtilityAction::Hold, target: Vec2::new(16.0, 30.0), receiver_id: None };
+        execute(&mut world, carrier, dribble);
+        let player = world.players.iter().find(|p| p.id == carrier).unwrap();
+        assert!(player.position.y > 20.0);
+        assert!(player.has_ball);
+        assert_eq!(world.ball.owner, Some(carrier));
+        assert_eq!((world.ball.position.x, world.ball.position.y), (player.position.x, player.position.y));
+        assert_eq!(world.events.len(), events);
+    }
+    
+    #[test]
+    fn possession_changes_are_logged_with_ids_periods_and_outcomes() {
+        let mut world = GameWorld::new_5v5();
+        let (home, away) = ([1, 2, 3], [6, 7]);
+        let first_id = world.next_event_id;
+        let pass_to = |world: &mut GameWorld, passer: u32, receiver: u32| {
+            let target = world.players.iter().find(|p| p.id == receiver).unwrap().position;
+            let pass = BallAction { action: UtilityAction::PassSafe, target, receiver_id: Some(receiver) };
+            execute(world, passer, pass);
+        };
+        
+        // 완성된 패스, 가로챈 패스, 태클 순서로 소유권이 바뀜
+        give_ball(&mut world, home[0], Vec2::new(16.0, 20.0));
+        pass_to(&mut world, home[0], home[1]);
+        world.record_possession_change(None, Some(home[1]));
+        assert_eq!(world.last_completed_pass, Some((home[0], home[1])));
+        pass_to(&mut world, home[1], home[2]);
+        world.record_possession_change(None, Some(away[0]));
+        assert_eq!(world.last_completed_pass, None);
+        world.record_possession_change(Some(away[0]), Some(home[2]));
+        
+        let log: Vec<(String, String, String)> = world.events[world.events.len() - 6..].iter()
+            .map(|e| (format!("{:?}", e.event_type), e.player_id.clone(), format!("{:?}", e.outcome)))
+            .collect();
+        let expected = [
+            ("Pass", home[0], "Complete"),
+            ("Pass", home[1], "Incomplete"),
+            ("Interception", away[0], "Success"),
+            ("Turnover", home[1], "Failure"),
+            ("Tackle", home[2], "Success"),
+            ("Turnover", away[0], "Failure"),
+        ].map(|(event_type, player_id, outcome)| (event_type.to_string(), player_id.to_string(), outcome.to_string()));
+        assert_eq!(log, expected);
+        
+        // 이벤트 ID는 순서대로 하나씩 증가하고 현재 피리어드를 기록
+        for (offset, event) in world.events.iter().filter(|e| e.period == "H1").enumerate() {
+            assert_eq!(event.id, format!("evt-{:06}", first_id + offset as u64));
+        }
+        world.start_period(Period::H2);
+        let player = world.players[0].clone();
+        world.push_event(EventType::Press, &player, EventPayload::Empty, EventOutcome::Success);
+        let last = world.events.last().unwrap();
+        assert_eq!(last.period, "H2");
+        assert_eq!(last.id, format!("evt-{:06}", world.next_event_id - 1));
+    }
+    
+    #[test]
+    fn goal_counts_score_and_assist_then_conceding_team_kicks_off() {
+        let mut world = GameWorld::new_5v5();
+        let (passer, scorer) = (1, 2);
+        give_ball(&mut world, passer, Vec2::new(16.0, 30.0));
+        let target = world.players.iter().find(|p| p.id == scorer).unwrap().position;
+        let pass = BallAction { action: UtilityAction::PassSafe, target, receiver_id: Some(scorer) };
+        execute(&mut world, passer, pass);
+        world.record_possession_change(None, Some(scorer));
+        
+        // 득점자의 마지막 터치 후 공이 골라인을 넘어감
+        let length = world.config.pitch.length;
+        world.ball = Ball::new(16.0, length - 0.2);
+        world.ball.velocity = Vec2::new(0.0, 10.0);
+        for player in &mut world.players {
+            player.has_ball = false;
+            if player.position.distance(&world.ball.position) < 6.0 {
+                player.position.y = length / 2.0;
+            }
+        }
+        world.tick();
+        
+        assert_eq!((world.match_state.home_score, world.match_state.away_score), (1, 0));
+        let goal = world.events.iter().find(|e| matches!(e.event_type, EventType::Goal)).expect("goal event");
+        assert_eq!(goal.player_id, scorer.to_string());
+        let EventPayload::Goal { scorer_id, assist_id } = &goal.payload else {
+            panic!("goal payload expected");
+        };
+        assert_eq!(scorer_id, &scorer.to_string());
+        assert_eq!(assist_id.as_deref(), Some(passer.to_string().as_str()));
+        
+        // 실점한 팀이 센터에서 킥오프하고 양 팀은 자기 진영으로 돌아감
+        let center = Vec2::new(world.config.pitch.width / 2.0, length / 2.0);
+        let taker = world.ball.owner.and_then(|id| world.find_player(id)).expect("kickoff taker");
+        assert_eq!(taker.team_id, 1);
+        assert_eq!((world.ball.position.x, world.ball.position.y), (center.x, center.y));
+        assert!(world.pending_restart.is_none() && world.last_completed_pass.is_none());
+        assert!(world.players.iter().filter(|p| p.team_id == 0).all(|p| p.position.y < center.y));
+        assert!(world.players.iter().filter(|p| p.team_id == 1 && p.id != taker.id).all(|p| p.position.y > center.y));
+    }
+    
+    #[test]
+    fn out_of_play_restart_depends_on_the_last_touch() {
+        let mut world = GameWorld::new_5v5();
+        let (home, away) = (1, 6);
+        let (width, length) = (world.config.pitch.width, world.config.pitch.length);
+        let restart_after = |world: &mut GameWorld, last_touch: u32, exit_point: Vec2, exit: BoundaryExit| {
+            give_ball(world, last_touch, Vec2::new(16.0, 25.0));
+            world.ball = Ball::new(exit_point.x, exit_point.y);
+            world.award_restart(exit);
+            let restart = world.pending_restart.expect("restart should be pending");
+            assert!(matches!(world.match_state.phase, MatchPhase::Restart));
+            (restart.kind, restart.team_id, (restart.location.x, restart.location.y))
+        };
+        
+        // 터치라인: 마지막으로 만진 팀의 상대가 나간 지점에서 스로인
+        let throw_in = restart_after(&mut world, home, Vec2::new(-0.5, 18.0), BoundaryExit::Touchline);
+        assert_eq!(throw_in, (SetPieceKind::ThrowIn, 1, (0.0, 18.0)));
+        
+        // 골라인: 공격 팀이 마지막이면 골킥, 수비 팀이 마지막이면 코너킥
+        let goal_line = BoundaryExit::GoalLine { defending_team: 1 };
+        let goal_kick = restart_after(&mut world, home, Vec2::new(5.0, length + 0.5), goal_line);
+        assert_eq!(goal_kick, (SetPieceKind::GoalKick, 1, (width / 2.0, length - world.config.pitch.goal_area_depth)));
+        assert!(world.pending_restart.is_some_and(|restart| world.find_player(restart.taker_id).unwrap().is_goalkeeper()));
+        let corner = restart_after(&mut world, away, Vec2::new(width - 5.0, length + 0.5), goal_line);
+        assert_eq!(corner, (SetPieceKind::Corner, 0, (width, length)));
+        
+        // 밖으로 나간 패스는 실패로 기록
+        give_ball(&mut world, home, Vec2::new(16.0, 25.0));
+        let pass = BallAction { action: UtilityAction::PassSafe, target: Vec2::new(0.0, 25.0), receiver_id: Some(2) };
+        execute(&mut world, home, pass);
+        world.ball.position = Vec2::new(-0.5, 25.0);
+        world.award_restart(BoundaryExit::Touchline);
+        let event = world.events.iter().rev().find(|e| matches!(e.event_type, EventType::Pass)).unwrap();
+        assert!(matches!(event.outcome, EventOutcome::Incomplete));
+        assert_eq!(event.player_id, home.to_string());
+    }
+    
+    /// 시간에 따라 정해진 의도 스트림
+    fn scripted_intents(time_ms: u64) -> Vec<Intent> {
+        let player_id = ((time_ms / 1000) % 10) as u32;
+        let action = match (time_ms / 1000) % 3 {
+            0 => Action::MoveToBall,
+            1 => Action::FindPassOption,
+            _ => Action::Press {
+                target: decision_plugin::Vec2::new(34.0, 52.5),
+            },
+        };
+        vec![Intent::new(player_id, IntentStatus::New, Some(action), time_ms)]
+    }
+    
+    fn run_match(seed: u64, ticks: usize) -> GameWorld {
+        let mut world = GameWorld::with_seed(seed);
+        for tick in 0..ticks {
+            if tick % 10 == 0 {
+                world.update_intents(scripted_intents(world.match_state.time_ms));
+            }
+            world.tick();
+        }
+        world
+    }
+    
+    #[test]
+    fn same_seed_produces_identical_event_log() {
+        let first = run_match(42, 3000);
+        let second = run_match(42, 3000);
+        
+        assert!(!first.events.is_empty());
+        assert_eq!(
+            serde_json::to_string(&first.events).unwrap(),
+            serde_json::to_string(&second.events).unwrap(),
+        );
+        assert_eq!(first.ball.position.x.to_bits(), second.ball.position.x.to_bits());
+        assert_eq!(first.ball.position.y.to_bits(), second.ball.position.y.to_bits());
+    }
+    
+    #[test]
+    fn advance_runs_whole_steps_regardless_of_frame_rate() {
+        let mut smooth = GameWorld::with_seed(7);
+        let mut choppy = GameWorld::with_seed(7);
+        
+        // 144fps 프레임과 불규칙한 프레임으로 같은 10초 진행
+        for _ in 0..1440 {
+            smooth.advance(1.0 / 144.0, 1.0);
+        }
+        for frame in 0..400 {
+            let seconds = if frame % 2 == 0 { 0.013 } else { 0.037 };
+            choppy.advance(seconds, 1.0);
+        }
+        
+        assert_eq!(smooth.match_state.time_ms, 10_000);
+        assert_eq!(choppy.match_state.time_ms, 10_000);
+        assert_eq!(
+            serde_json::to_string(&smooth.events).unwrap(),
+            serde_json::to_string(&choppy.events).unwrap(),
+        );
+    }
+    
+    #[test]
+    fn speed_multiplier_changes_step_count_not_size() {
+        let mut world = GameWorld::with_seed(7);
+        let steps = world.advance(1.0, 2.0);
+        
+        assert_eq!(steps, 20);
+        assert_eq!(world.match_state.time_ms, 2_000);
+    }
+    
+    #[test]
+    fn match_switches_ends_at_half_time_and_finishes() {
+        let mut world = GameWorld::with_seed(3);
+        world.config.rules = MatchRules {
+            half_length_ms: 60_000,
+            max_stoppage_ms: 0,
+            ..MatchRules::default()
+        };
+        
+        world.advance(60.0, 1.0);
+        assert_eq!(world.match_state.period, Period::H2);
+        assert!(world.match_state.ends_swapped);
+        
+        world.advance(60.0, 1.0);
+        assert!(world.match_state.is_finished());
+        let finished_at = world.match_state.time_ms;
+        world.advance(10.0, 1.0);
+        assert_eq!(world.match_state.time_ms, finished_at);
+    }
+    
+    #[test]
+    fn stoppage_time_is_half_the_dead_ball_time_rounded_up_and_capped() {
+        let mut world = GameWorld::with_seed(3);
+        world.config.rules = MatchRules {
+            half_length_ms: 60_000,
+            max_stoppage_ms: 120_000,
+            ..MatchRules::default()
+        };
+        // 정규 시간 마지막 스텝 직전까지 진행한 뒤 데드볼 시간을 정함
+        let set_dead_ball = |world: &mut GameWorld, dead_ball_ms: u64| {
+            while world.match_state.period_elapsed_ms < 60_000 - TICK_MS {
+                world.tick();
+            }
+            world.dead_ball_ms = dead_ball_ms;
+        };
+        
+        // 데드볼 61초 → 절반을 분 단위로 올려 추가시간 1분
+        set_dead_ball(&mut world, 61_000);
+        while world.match_state.period == Period::H1 {
+            world.tick();
+        }
+        assert_eq!(world.match_state.time_ms, 120_000);
+        
+        // 데드볼 10분 → 5분이지만 상한 2분
+        set_dead_ball(&mut world, 600_000);
+        world.tick();
+        assert_eq!(world.match_state.stoppage_ms, Some(120_000));
+        while !world.match_state.is_finished() {
+            world.tick();
+        }
+        assert_eq!(world.match_state.time_ms, 120_000 + 180_000);
+    }
+    
+    #[test]
+    fn level_cup_match_is_decided_by_a_deterministic_shootout() {
+        let run = |seed| {
+            let mut world = GameWorld::with_seed(seed);
+            world.config.rules = MatchRules {
+                half_length_ms: 100,
+                extra_time_length_ms: 100,
+                max_stoppage_ms: 0,
+                ..MatchRules::cup()
+            };
+            world.advance(0.3, 1.0);
+            assert_eq!(world.match_state.phase, MatchPhase::Shootout);
+            world.resolve_shootout();
+            world
+        };
+        
+        let first = run(9);
+        let second = run(9);
+        assert!(first.match_state.is_finished());
+        assert!(first.match_state.winner().is_some());
+        // 승부차기 킥은 페널티 xG를 기록하지만 팀 xG에는 포함되지 않음
+        assert!(first.events.iter().any(|event| matches!(event.payload, EventPayload::Shot { xg, .. } if xg == PENALTY_XG)));
+        assert_eq!(first.team_xg(0) + first.team_xg(1), 0.0);
+        assert_eq!(
+            serde_json::to_string(&first.events).unwrap(),
+            serde_json::to_string(&second.events).unwrap(),
+        );
+    }
+    
+    #[test]
+    fn shootout_takers_put_keepers_last_and_match_the_smaller_side() {
+        let mut world = GameWorld::with_seed(9);
+        let home_keeper = world.goalkeeper(0).unwrap().id;
+        let away_keeper = world.goalkeeper(1).unwrap().id;
+        // 골키퍼의 자신감이 가장 높아도 마지막 키커
+        for player in &mut world.players {
+            if player.is_goalkeeper() {
+                player.persona.confidence = 1.0;
+                player.persona.patience = 1.0;
+            }
+        }
+        world.send_off(outfield(&world, 1)[0]);
+        world.start_shootout();
+        
+        let takers = &world.match_state.shootout.as_ref().unwrap().takers;
+        assert_eq!((takers[0].len(), takers[1].len()), (4, 4));
+        assert!(!takers[0].contains(&home_keeper));
+        assert_eq!(takers[1].last(), Some(&away_keeper));
+        assert!(takers[0].iter().all(|id| world.find_player(*id).is_some_and(|p| p.team_id == 0)));
+    }
+    
+    #[test]
+    fn goalkeeper_claims_loose_balls_in_the_box_and_never_leaves_it() {
+        let mut world = GameWorld::with_seed(5);
+        world.pending_restart = None;
+        let (width, length) = (world.config.pitch.width, world.config.pitch.length);
+        let goal = defended_goal(&world.config.pitch, 1, false);
+        let keeper = world.goalkeeper(1).unwrap();
+        for player in &mut world.players {
+            player.has_ball = false;
+            if !player.is_goalkeeper() {
+                player.position.y = length / 2.0;
+            }
+        }
+        
+        // 박스 안 루즈볼에 가장 가까우면 직접 처리
+        world.ball = Ball::new(20.0, length - 4.0);
+        assert!(world.goalkeeper_target(&keeper).distance(&world.ball.position) < 1e-4);
+        
+        // 슈팅이 날아오는 중에는 공-골문 라인을 지킴
+        let shooter = outfield(&world, 0)[0];
+        world.pending_shot = Some(PendingShot { shooter_id: shooter, team_id: 0, taken_at_ms: 0, on_target: true });
+        assert!(world.goalkeeper_target(&keeper).distance(&goalkeeper_position(&goal, &world.ball.position)) < 1e-4);
+        world.pending_shot = None;
+        
+        // 공이 어디에 있든 목표는 페널티 박스 안
+        give_ball(&mut world, shooter, Vec2::new(16.0, 20.0));
+        assert!(world.goalkeeper_target(&keeper).distance(&goalkeeper_position(&goal, &world.ball.position)) < 1e-4);
+        world.ball.owner = None;
+        for x in [-2.0, 0.0, 8.0, 16.0, 24.0, width, width + 2.0] {
+            for y in [0.0, 20.0, length - 12.0, length - 4.0, length, length + 1.0] {
+                world.ball.position = Vec2::new(x, y);
+                let target = world.goalkeeper_target(&keeper);
+                assert!(in_penalty_area(&world.config.pitch, &goal, &target), "ball {:?} target {:?}", world.ball.position, target);
+            }
+        }
+    }
+    
+    /// 홈 팀 슈팅이 원정 골키퍼 옆 `lateral` 거리를 지나가는 순간의 선방 판정 (선방 시 캐치 여부)
+    fn scripted_save(seed: u64, lateral: f32, speed: f32, elapsed_ms: u64, height: f32) -> (GameWorld, Option<bool>) {
+        let mut world = GameWorld::with_seed(seed);
+        world.pending_restart = None;
+        let length = world.config.pitch.length;
+        let keeper_id = world.goalkeeper(1).unwrap().id;
+        for player in &mut world.players {
+            player.has_ball = false;
+            if player.id == keeper_id {
+                player.position = Vec2::new(16.0, length - 1.0);
+            }
+        }
+        let shooter = outfield(&world, 0)[0];
+        world.match_state.time_ms = 10_000;
+        world.pending_shot = Some(PendingShot { shooter_id: shooter, team_id: 0, taken_at_ms: 10_000 - elapsed_ms, on_target: true });
+        world.last_touch = Some(shooter);
+        let previous = Vec2::new(16.0 + lateral, length - 2.0);
+        world.ball = Ball::new(16.0 + lateral, length - 0.5);
+        world.ball.velocity = Vec2::new(0.0, speed);
+        world.ball.height = height;
+        world.attempt_save(previous);
+        assert!(world.pending_shot.is_none());
+        let caught = world.events.iter().find_map(|e| match e.payload {
+            EventPayload::Save { caught, .. } => Some(caught),
+            _ => None,
+        });
+        (world, caught)
+    }
+    
+    #[test]
+    fn goalkeeper_saves_within_reach_and_catches_only_slower_shots() {
+        let seeds = 0..40;
+        
+        // 손이 닿는 슈팅은 대부분 막고, 느린 공은 잡기도 함
+        let close: Vec<(GameWorld, Option<bool>)> = seeds.clone().map(|seed| scripted_save(seed, 0.3, 15.0, 0, 0.0)).collect();
+        assert!(close.iter().filter(|(_, saved)| saved.is_some()).count() > 30);
+        let (caught, _) = close.iter().find(|(_, saved)| *saved == Some(true)).expect("a slow shot should be caught");
+        let keeper = caught.goalkeeper(1).unwrap();
+        assert_eq!(caught.ball.owner, Some(keeper.id));
+        assert_eq!(caught.ball.velocity.length(), 0.0);
+        assert!(matches!(caught.events.last().unwrap().event_type, EventType::Turnover));
+        
+        // 빠른 슈팅은 잡지 못하고 옆으로 쳐냄
+        for (world, saved) in seeds.clone().map(|seed| scripted_save(seed, 0.3, 30.0, 0, 0.0)) {
+            if saved.is_some() {
+                assert_eq!(saved, Some(false));
+                assert_eq!(world.ball.owner, None);
+                assert!(world.ball.velocity.y < 0.0 && world.ball.velocity.x != 0.0);
+            }
+        }
+        
+        // 반응 시간 전에는 기본 범위 밖을 막지 못하고, 이후에는 다이빙으로 닿음
+        assert!(seeds.clone().all(|seed| scripted_save(seed, 2.0, 15.0, 0, 0.0).1.is_none()));
+        assert!(seeds.clone().any(|seed| scripted_save(seed, 2.0, 15.0, 500, 0.0).1.is_some()));
+        // 손이 닿지 않는 높이
+        assert!(seeds.clone().all(|seed| scripted_save(seed, 0.3, 15.0, 0, GOALKEEPER_HANDLING_HEIGHT + 0.5).1.is_none()));
+        
+        // 골문을 벗어난 슈팅은 판정만 끝냄
+        let mut world = GameWorld::with_seed(1);
+        world.pending_shot = Some(PendingShot { shooter_id: outfield(&world, 0)[0], team_id: 0, taken_at_ms: 0, on_target: false });
+        let keeper = world.goalkeeper(1).unwrap().position;
+        world.ball = Ball::new(keeper.x, keeper.y + 0.5);
+        world.attempt_save(Vec2::new(keeper.x, keeper.y - 0.5));
+        assert!(world.pending_shot.is_none());
+        assert!(!world.events.iter().any(|e| matches!(e.event_type, EventType::Save)));
+    }
+    
+    /// 홈 선수가 `offender_gap` 거리에서 `at`에 있는 원정 선수에게 반칙
+    fn scripted_foul(at: Vec2, offender_gap: f32) -> (GameWorld, Player, Player) {
+        let mut world = GameWorld::with_seed(4);
+        world.pending_restart = None;
+        let offender_id = outfield(&world, 0)[0];
+        let victim_id = outfield(&world, 1)[0];
+        for player in &mut world.players {
+            player.has_ball = false;
+            if player.id == victim_id {
+                player.position = at;
+            } else if player.id == offender_id {
+                player.position = Vec2::new(at.x + offender_gap, at.y);
+            }
+        }
+        let offender = world.find_player(offender_id).unwrap();
+        let victim = world.find_player(victim_id).unwrap();
+        world.commit_foul(&offender, &victim);
+        (world, offender, victim)
+    }
+    
+    #[test]
+    fn foul_restart_depends_on_the_location_and_the_offence() {
+        // 박스 안 직접 반칙은 페널티킥
+        let pitch = MatchConfig::five_a_side().pitch;
+        let (world, offender, victim) = scripted_foul(Vec2::new(pitch.width / 2.0 + 3.0, 5.0), 1.0);
+        let restart = world.pending_restart.unwrap();
+        assert_eq!(restart.kind, SetPieceKind::Penalty);
+        assert_eq!(restart.team_id, 1);
+        assert!(restart.location.distance(&Vec2::new(pitch.width / 2.0, pitch.penalty_spot_distance)) < 1e-4);
+        let foul = world.events.iter().find(|e| matches!(e.event_type, EventType::Foul)).unwrap();
+        assert_eq!(foul.player_id, offender.id.to_string());
+        assert_eq!(foul.team_id, "0");
+        match &foul.payload {
+            EventPayload::Foul { on_player_id, restart } => {
+                assert_eq!(on_player_id, &victim.id.to_string());
+                assert_eq!(*restart, SetPieceKind::Penalty);
+            }
+            other => panic!("unexpected payload {:?}", other),
+        }
+        
+        // 박스 밖 직접 반칙은 반칙 위치에서 직접 프리킥
+        let at = pitch.center();
+        let (world, _, _) = scripted_foul(at, 1.0);
+        let restart = world.pending_restart.unwrap();
+        assert_eq!((restart.kind, restart.team_id), (SetPieceKind::DirectFreeKick, 1));
+        assert!(restart.location.distance(&at) < 1e-4);
+        
+        // 방해(거리가 있는 반칙)는 간접 프리킥, 골 에어리어 안이면 골 에어리어 라인으로
+        let (world, _, _) = scripted_foul(Vec2::new(pitch.width / 2.0 + 3.0, 1.0), 2.0);
+        let restart = world.pending_restart.unwrap();
+        assert_eq!(restart.kind, SetPieceKind::IndirectFreeKick);
+        assert!(restart.location.distance(&Vec2::new(pitch.width / 2.0 + 3.0, pitch.goal_area_depth)) < 1e-4);
+        assert!(world.events.iter().any(|e| matches!(
+            e.payload,
+            EventPayload::Foul { restart: SetPieceKind::IndirectFreeKick, .. }
+        )));
+    }
+    
+    #[test]
+    fn second_yellow_sends_off_and_a_field_player_replaces_a_sent_off_keeper() {
+        let mut world = GameWorld::with_seed(4);
+        let player = world.find_player(outfield(&world, 0)[0]).unwrap();
+        let cards = |world: &GameWorld| -> Vec<(Card, bool)> {
+            world.events.iter().filter_map(|e| match e.payload {
+                EventPayload::Card { card, sent_off } => Some((card, sent_off)),
+                _ => None,
+            }).collect()
+        };
+        
+        // 첫 경고는 기록만, 두 번째 경고는 퇴장
+        world.show_card(&player, Card::Yellow);
+        assert_eq!(cards(&world), vec![(Card::Yellow, false)]);
+        assert!(world.find_player(player.id).is_some());
+        world.show_card(&player, Card::Yellow);
+        assert_eq!(cards(&world), vec![(Card::Yellow, false), (Card::Yellow, true)]);
+        assert!(world.find_player(player.id).is_none());
+        assert_eq!(world.sent_off.iter().map(|p| p.id).collect::<Vec<_>>(), vec![player.id]);
+        assert_eq!(world.players_on_pitch(0), world.players_on_pitch(1) - 1);
+        
+        // 공을 가진 골키퍼가 퇴장당하면 공을 놓고, 골문에 가장 가까운 필드 플레이어가 골키퍼가 됨
+        let keeper = world.goalkeeper(0).unwrap();
+        let stand_in = outfield(&world, 0)[0];
+        give_ball(&mut world, keeper.id, keeper.position);
+        world.players.iter_mut().find(|p| p.id == stand_in).unwrap().position = Vec2::new(world.config.pitch.width / 2.0, 4.0);
+        world.show_card(&keeper, Card::Red);
+        assert_eq!(cards(&world).last(), Some(&(Card::Red, true)));
+        assert!(world.find_player(keeper.id).is_none());
+        assert_eq!(world.ball.owner, None);
+        let replacement = world.goalkeeper(0).unwrap();
+        assert_eq!(replacement.id, stand_in);
+        assert!(replacement.is_goalkeeper());
+        assert_eq!(world.players.iter().filter(|p| p.team_id == 0 && p.is_goalkeeper()).count(), 1);
+    }
+    
+    /// 원정 수비 라인 뒤에 선 홈 공격수에게 패스하고, 패스 순간 기록된 오프사이드 위치를 반환
+    fn scripted_offside_pass(world: &mut GameWorld, restart_kind: Option<SetPieceKind>) -> (u32, u32, Option<Vec2>) {
+        world.pending_restart = None;
+        let (passer, receiver) = (outfield(world, 0)[0], outfield(world, 0)[1]);
+        let (width, length) = (world.config.pitch.width, world.config.pitch.length);
+        for player in &mut world.players {
+            player.has_ball = false;
+            if player.id == receiver {
+                player.position = Vec2::new(width / 2.0, length - 15.0);
+            } else if !player.is_goalkeeper() {
+                player.position.y = length / 2.0 + 5.0;
+            }
+        }
+        give_ball(world, passer, Vec2::new(width / 2.0, length / 2.0));
+        world.restart_kind = restart_kind;
+        let target = world.find_player(receiver).unwrap().position;
+        let pass = BallAction { action: UtilityAction::PassSafe, target, receiver_id: Some(receiver) };
+        execute(world, passer, pass);
+        (passer, receiver, world.pending_pass.and_then(|pass| pass.offside_at))
+    }
+    
+    #[test]
+    fn receiving_a_pass_from_an_offside_position_gives_an_indirect_free_kick() {
+        let mut world = GameWorld::from_config(MatchConfig::eleven_a_side(), 6);
+        let (passer, receiver, offside_at) = scripted_offside_pass(&mut world, None);
+        let offside_at = offside_at.expect("receiver is beyond the second-last defender");
+        
+        // 리시버가 공을 받는 순간 패스는 실패, 오프사이드 이벤트와 수비 팀 간접 프리킥
+        world.ball.owner = Some(receiver);
+        world.record_possession_change(None, Some(receiver));
+        assert!(world.pending_pass.is_none());
+        assert!(world.last_completed_pass.is_none());
+        let pass = world.events.iter().rev().find(|e| matches!(e.event_type, EventType::Pass)).unwrap();
+        assert_eq!(pass.player_id, passer.to_string());
+        assert!(matches!(pass.outcome, EventOutcome::Incomplete));
+        let offside = world.events.iter().find(|e| matches!(e.event_type, EventType::Offside)).unwrap();
+        assert_eq!(offside.player_id, receiver.to_string());
+        assert!(offside.location.distance(&offside_at) < 1e-4);
+        assert!(matches!(&offside.payload, EventPayload::Offside { passer_id } if *passer_id == passer.to_string()));
+        let restart = world.pending_restart.unwrap();
+        assert_eq!((restart.kind, restart.team_id), (SetPieceKind::IndirectFreeKick, 1));
+        assert!(restart.location.distance(&offside_at) < 1e-4);
+    }
+    
+    #[test]
+    fn offside_is_not_called_from_exempt_restarts_or_when_disabled() {
+        // 스로인, 골킥, 코너킥에서 바로 받은 패스는 면제, 프리킥은 적용
+        for (kind, applies) in [
+            (SetPieceKind::ThrowIn, false),
+            (SetPieceKind::GoalKick, false),
+            (SetPieceKind::Corner, false),
+            (SetPieceKind::IndirectFreeKick, true),
+        ] {
+            let mut world = GameWorld::from_config(MatchConfig::eleven_a_side(), 6);
+            let (_, _, offside_at) = scripted_offside_pass(&mut world, Some(kind));
+            assert_eq!(offside_at.is_some(), applies, "{:?}", kind);
+            assert!(world.restart_kind.is_none());
+        }
+        
+        // 오프사이드가 없는 5인제에서는 판정하지 않음
+        let mut world = GameWorld::with_seed(6);
+        assert!(!world.config.rules.offside);
+        let (_, receiver, offside_at) = scripted_offside_pass(&mut world, None);
+        assert!(offside_at.is_none());
+        world.ball.owner = Some(receiver);
+        world.record_possession_change(None, Some(receiver));
+        assert!(!world.events.iter().any(|e| matches!(e.event_type, EventType::Offside)));
+        assert!(world.pending_restart.is_none());
+    }
+    
+    #[test]
+    fn balls_at_head_height_are_headed_clear_or_on() {
+        let mut world = GameWorld::with_seed(5);
+        let (home, away) = (outfield(&world, 0), outfield(&world, 1));
+        let aerial_ball = |world: &mut GameWorld, position: Vec2, height: f32| {
+            world.ball.position = position;
+            world.ball.velocity = Vec2::new(0.0, 10.0);
+            world.ball.height = height;
+            world.ball.owner = None;
+            world.last_kick = None;
+        };
+        let place = |world: &mut GameWorld, player_id: u32, position: Vec2| {
+            world.players.iter_mut().find(|p| p.id == player_id).unwrap().position = position;
+        };
+        
+        // 자기 진영의 수비수는 띄운 패스를 골문 반대쪽으로 걷어냄
+        give_ball(&mut world, home[0], Vec2::new(16.0, 20.0));
+        let pass = BallAction { action: UtilityAction::PassSafe, target: Vec2::new(16.0, 45.0), receiver_id: Some(home[1]) };
+        execute(&mut world, home[0], pass);
+        for player in &mut world.players {
+            player.position.x = if player.team_id == 0 { 2.0 } else { 30.0 };
+        }
+        place(&mut world, away[0], Vec2::new(16.0, 45.5));
+        aerial_ball(&mut world, Vec2::new(16.0, 45.0), HEADER_HEIGHT + 0.5);
+        assert!(!attempt_header(&mut world), "too high to head");
+        aerial_ball(&mut world, Vec2::new(16.0, 45.0), 1.8);
+        assert!(attempt_header(&mut world));
+        assert_eq!(world.last_touch, Some(away[0]));
+        assert!(world.pending_pass.is_none());
+        assert!(world.ball.velocity.y < 0.0);
+        assert!((world.ball.velocity.length() - HEADER_SPEED).abs() < 1e-3);
+        let recent: Vec<String> = world.events.iter().rev().take(3).map(|e| format!("{:?}", e.event_type)).collect();
+        assert_eq!(recent, ["Turnover", "Interception", "Pass"]);
+        
+        // 상대 진영의 공격수는 앞쪽 동료에게 떨궈 줌
+        place(&mut world, home[1], Vec2::new(16.0, 35.0));
+        place(&mut world, home[2], Vec2::new(20.0, 42.0));
+        aerial_ball(&mut world, Vec2::new(16.0, 35.0), 2.0);
+        assert!(attempt_header(&mut world));
+        let flick = world.pending_pass.expect("headed pass should be in flight");
+        assert_eq!((flick.passer_id, flick.receiver_id), (home[1], home[2]));
+        assert!(world.ball.velocity.x > 0.0 && world.ball.velocity.y > 0.0);
+        assert_eq!(world.last_kick, Some((home[1], world.match_state.time_ms)));
+    }
+    
+    #[test]
+    fn match_config_sets_team_size_and_pitch() {
+        for config in [MatchConfig::five_a_side(), MatchConfig::seven_a_side(), MatchConfig::eleven_a_side()] {
+            let world = GameWorld::from_config(config.clone(), 1);
+            assert_eq!(world.players.len(), config.team_size * 2);
+            assert!(world.bench.iter().all(|bench| bench.len() == config.bench_size));
+            let mut ids: Vec<u32> = world.players.iter().chain(world.bench.iter().flatten()).map(|p| p.id).collect();
+            ids.sort();
+            ids.dedup();
+            assert_eq!(ids.len(), (config.team_size + config.bench_size) * 2);
+            for team_id in 0..2 {
+                let team: Vec<&Player> = world.players.iter().filter(|p| p.team_id == team_id).collect();
+                assert_eq!(team.len(), config.team_size);
+                assert_eq!(team.iter().filter(|p| p.is_goalkeeper()).count(), 1);
+            }
+            assert!(world.players.iter().all(|p| {
+                (0.0..=config.pitch.width).contains(&p.position.x)
+                    && (0.0..=config.pitch.length).contains(&p.position.y)
+            }));
+            let goal = defended_goal(&config.pitch, 1, false);
+            assert_eq!(goal.center.y, config.pitch.length);
+            assert_eq!(goal.width, config.pitch.goal_width);
+        }
+    }
+    
+    #[test]
+    fn turnover_fires_press_on_loss_for_nearby_players() {
+        let mut world = GameWorld::with_seed(7);
+        world.tactics[0].triggers = vec![Trigger::PressOnLoss];
+        world.tactics[1].triggers.clear();
+        world.pending_restart = None;
+        
+        let loser = world.players.iter().find(|p| p.team_id == 0 && !p.is_goalkeeper()).unwrap().clone();
+        world.push_event(EventType::Turnover, &loser, EventPayload::Empty, EventOutcome::Failure);
+        world.evaluate_triggers();
+        
+        let firing = world.trigger_log.last().expect("PressOnLoss should fire");
+        assert_eq!(firing.trigger, Trigger::PressOnLoss);
+        assert_eq!(firing.team_id, 0);
+        assert!(firing.player_ids.contains(&loser.id));
+        assert!(firing.player_ids.len() <= triggers::PRESS_ON_LOSS_PLAYERS);
+        for player_id in &firing.player_ids {
+            let intent = world.current_intents.iter().find(|i| i.player_id == *player_id).unwrap();
+            assert_eq!(intent.duration_ms, Some(triggers::PRESS_ON_LOSS_MS));
+        }
+        
+        // 트리거를 켜지 않은 팀은 반응하지 않음
+        let away = world.players.iter().find(|p| p.team_id == 1 && !p.is_goalkeeper()).unwrap().clone();
+        world.push_event(EventType::Turnover, &away, EventPayload::Empty, EventOutcome::Failure);
+        world.evaluate_triggers();
+        assert_eq!(world.trigger_log.len(), 1);
+    }
+    
+    #[test]
+    fn substitutions_wait_for_a_stoppage_and_respect_the_limit() {
+        let mut world = GameWorld::with_seed(3);
+        world.config.rules.max_substitutions = 1;
+        while world.pending_restart.is_some() {
+            world.tick();
+        }
+        
+        let outfield: Vec<u32> = world.players.iter()
+            .filter(|p| p.team_id == 0 && !p.is_goalkeeper())
+            .map(|p| p.id)
+            .collect();
+        let (player_out, player_in) = (outfield[0], world.bench[0][0].id);
+        let slot = world.players.iter().position(|p| p.id == player_out).unwrap();
+        world.current_intents.push(Intent::new(player_out, IntentStatus::New, Some(Action::HoldPosition), world.match_state.time_ms));
+        
+        assert_eq!(world.request_substitution(0, player_out, player_in), Ok(()));
+        assert_eq!(world.request_substitution(0, outfield[1], player_in), Err(SubstitutionError::AlreadyRequested(player_in)));
+        assert_eq!(world.request_substitution(0, outfield[1], world.bench[0][1].id), Err(SubstitutionError::LimitReached));
+        assert_eq!(world.request_substitution(1, player_out, world.bench[1][0].id), Err(SubstitutionError::PlayerNotOnPitch(player_out)));
+        
+        // 인플레이 중에는 실행되지 않음
+        world.tick();
+        assert_eq!(world.players[slot].id, player_out);
+        
+        let mut ticks = 0;
+        while world.substitutions_made[0] == 0 && ticks < 20_000 {
+            world.tick();
+            ticks += 1;
+        }
+        assert!(world.pending_restart.is_some(), "substitution should happen at a stoppage");
+        assert_eq!(world.players[slot].id, player_in);
+        assert!(world.current_intents.iter().any(|intent| intent.player_id == player_in));
+        assert!(world.substituted.iter().any(|p| p.id == player_out));
+        let event = world.events.iter().rev().find(|e| matches!(e.event_type, EventType::Substitution)).unwrap();
+        assert_eq!(event.player_id, player_in.to_string());
+        
+        // 나간 선수는 다시 들어올 수 없음
+        assert_eq!(world.request_substitution(0, outfield[1], player_out), Err(SubstitutionError::PlayerNotOnBench(player_out)));
+        assert_eq!(world.request_substitution(0, outfield[1], world.bench[0][0].id), Err(SubstitutionError::LimitReached));
+    }
+    
+    #[test]
+    fn substitutions_apply_at_half_time_and_after_goals() {
+        let mut world = GameWorld::with_seed(3);
+        let outfield = outfield(&world, 0);
+        give_ball(&mut world, outfield[0], Vec2::new(16.0, 20.0));
+        
+        let (player_out, player_in) = (outfield[1], world.bench[0][0].id);
+        let slot = world.players.iter().position(|p| p.id == player_out).unwrap();
+        assert_eq!(world.request_substitution(0, player_out, player_in), Ok(()));
+        world.start_period(Period::H2);
+        assert_eq!(world.players[slot].id, player_in);
+        assert!(world.pending_substitutions.is_empty());
+        let event = world.events.iter().rev().find(|e| matches!(e.event_type, EventType::Substitution)).unwrap();
+        assert_eq!(event.period, "H2");
+        
+        // 득점 후 킥오프 전에도 교체
+        let (player_out, player_in) = (outfield[2], world.bench[0][0].id);
+        let slot = world.players.iter().position(|p| p.id == player_out).unwrap();
+        give_ball(&mut world, outfield[0], Vec2::new(16.0, 20.0));
+        assert_eq!(world.request_substitution(0, player_out, player_in), Ok(()));
+        world.award_goal(1);
+        assert_eq!(world.players[slot].id, player_in);
+        assert_eq!(world.substitutions_made[0], 2);
+    }
+    
+    #[test]
+    fn different_seeds_diverge() {
+        let first = run_match(1, 3000);
+        let second = run_match(2, 3000);
+        
+        assert_ne!(
+            serde_json::to_string(&first.events).unwrap(),
+            serde_json::to_string(&second.events).unwrap(),
+        );
+    }
+    
+    #[test]
+    fn stats_follow_the_event_stream_tick_by_tick() {
+        let world = run_match(42, 3000);
+        let passes = world.events.iter().filter(|e| matches!(e.event_type, EventType::Pass)).count() as u32;
+        let stats = &world.stats;
+        
+        assert_eq!(stats.teams[0].line.passes_attempted + stats.teams[1].line.passes_attempted, passes);
+        let possession_ms = stats.teams[0].possession_ms + stats.teams[1].possession_ms;
+        assert!(possession_ms > 0 && possession_ms <= world.match_state.time_ms);
+        assert!(stats.teams.iter().all(|team| team.line.distance_m > 0.0));
+        assert!(stats.teams[0].line.intents_issued > 0);
+        assert!(stats.teams[0].line.intents_completed <= stats.teams[0].line.intents_issued);
+        let player_distance: f32 = stats.players.iter().filter(|p| p.team_id == 0).map(|p| p.line.distance_m).sum();
+        assert!((player_distance - stats.teams[0].line.distance_m).abs() < 1.0);
     }
 }
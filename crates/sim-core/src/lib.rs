@@ -7,7 +7,21 @@ pub mod physics;
 pub mod decision;
 pub mod events;
 pub mod game;
+pub mod shootout;
+pub mod contest;
+pub mod stamina;
+pub mod morale;
+pub mod config;
+pub mod tactics;
+pub mod triggers;
+pub mod substitution;
+pub mod behaviors;
+pub mod xg;
+pub mod pitch_control;
+pub mod stats;
 
 pub use types::*;
 pub use events::*;
 pub use game::*;
+pub use config::*;
+pub use substitution::*;
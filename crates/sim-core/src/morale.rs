@@ -0,0 +1,88 @@
+use crate::events::{Card, EventOutcome, EventPayload, EventType, MatchEvent};
+
+/// 킥오프 시 기본 사기
+pub const BASE_MORALE: f32 = 0.7;
+/// 득점한 팀 전원의 사기 변화
+pub const GOAL_FOR_SWING: f32 = 0.06;
+/// 실점한 팀 전원의 사기 변화
+pub const GOAL_AGAINST_SWING: f32 = -0.08;
+/// 득점자 본인의 추가 사기 변화 (자책골 제외)
+pub const SCORER_SWING: f32 = 0.1;
+/// 동료가 퇴장당한 팀 전원의 사기 변화
+pub const SENDING_OFF_SWING: f32 = -0.05;
+
+/// 스코어 차이 1골당 목표 사기 변화
+const GOAL_DIFFERENCE_EFFECT: f32 = 0.05;
+/// 목표 사기로 수렴하는 속도 (초당 비율)
+const SCORELINE_DRIFT_PER_SECOND: f32 = 0.005;
+
+/// 사기에 따른 과감함 배율 (0.58 ~ 1.18, 기본 사기에서 1.0)
+///
+/// 사기가 높으면 슈팅과 위험한 패스를 더 시도하고, 낮으면 안전한 선택을 선호
+pub fn boldness(morale: f32) -> f32 {
+    1.0 + 0.6 * (morale.clamp(0.0, 1.0) - BASE_MORALE)
+}
+
+/// 사기 변화 적용
+///
+/// 자신감이 높은 선수는 좋은 일에 더 크게 반응하고 나쁜 일은 덜 받아들임
+pub fn apply_swing(morale: f32, swing: f32, confidence: f32) -> f32 {
+    let scaled = if swing >= 0.0 {
+        swing * (0.5 + confidence)
+    } else {
+        swing * (1.5 - confidence)
+    };
+    (morale + scaled).clamp(0.0, 1.0)
+}
+
+/// 스코어에 따라 사기가 서서히 향하는 목표값
+pub fn scoreline_target(goal_difference: i32) -> f32 {
+    (BASE_MORALE + goal_difference as f32 * GOAL_DIFFERENCE_EFFECT).clamp(0.3, 1.0)
+}
+
+/// 목표 사기를 향해 서서히 이동
+pub fn drift_towards(morale: f32, target: f32, delta_time: f32) -> f32 {
+    morale + (target - morale) * (SCORELINE_DRIFT_PER_SECOND * delta_time).min(1.0)
+}
+
+/// 이벤트를 일으킨 선수 본인의 사기 변화 (골은 팀 단위로 따로 처리)
+pub fn event_swing(event: &MatchEvent) -> f32 {
+    let success = matches!(event.outcome, EventOutcome::Complete | EventOutcome::Success);
+    match (&event.event_type, &event.payload) {
+        (EventType::Pass, _) => if success { 0.004 } else { -0.02 },
+        (EventType::Shot, EventPayload::Shot { on_target, .. }) => if *on_target { 0.01 } else { -0.03 },
+        (EventType::Tackle, _) | (EventType::Interception, _) => if success { 0.03 } else { -0.01 },
+        (EventType::Save, _) => 0.05,
+        (EventType::Turnover, _) => -0.02,
+        (EventType::Foul, _) | (EventType::Offside, _) => -0.02,
+        (EventType::Card, EventPayload::Card { card: Card::Yellow, .. }) => -0.06,
+        _ => 0.0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn confidence_shapes_swings() {
+        let confident_gain = apply_swing(0.5, 0.1, 0.9) - 0.5;
+        let nervous_gain = apply_swing(0.5, 0.1, 0.1) - 0.5;
+        assert!(confident_gain > nervous_gain);
+
+        let confident_loss = 0.5 - apply_swing(0.5, -0.1, 0.9);
+        let nervous_loss = 0.5 - apply_swing(0.5, -0.1, 0.1);
+        assert!(nervous_loss > confident_loss);
+
+        assert_eq!(boldness(BASE_MORALE), 1.0);
+        assert!(boldness(1.0) > 1.0 && boldness(0.3) < 1.0);
+    }
+
+    #[test]
+    fn scoreline_pulls_morale_towards_target() {
+        assert!(scoreline_target(2) > scoreline_target(0));
+        assert!(scoreline_target(-2) < scoreline_target(0));
+        let drifted = drift_towards(0.4, scoreline_target(0), 10.0);
+        assert!(drifted > 0.4 && drifted < scoreline_target(0));
+    }
+}
@@ -1,41 +1,249 @@
-use crate::types::{Vec2, Ball, Player};
+use crate::types::{Vec2, Ball, Goal, Player};
+use crate::config::Pitch;
+use crate::contest::{distance_to_segment, loose_ball_weight};
+use rand::Rng;
 
 /// 물리 상수
-pub const FIELD_WIDTH: f32 = 68.0;
-pub const FIELD_HEIGHT: f32 = 105.0;
 pub const PLAYER_RADIUS: f32 = 0.5;
 pub const BALL_RADIUS: f32 = 0.11;
 pub const MIN_DISTANCE: f32 = 1.0; // 최소 충돌 회피 거리
+pub const BALL_ROLLING_DRAG: f32 = 0.95; // 구르는 공의 속도 비례 감쇠 (1/s)
+pub const BALL_ROLLING_RESISTANCE: f32 = 0.3; // 구르는 공의 일정 감속 (m/s²)
+pub const BALL_AIR_DRAG: f32 = 0.1; // 날아가는 공의 공기 저항 (1/s)
+pub const BALL_RESTITUTION: f32 = 0.5; // 바운드 반발 계수
+pub const BALL_BOUNCE_FRICTION: f32 = 0.8; // 바운드 시 수평 속도 유지 비율
+pub const GRAVITY: f32 = 9.81;
+pub const CONTROL_HEIGHT: f32 = 1.0; // 발/가슴으로 공을 잡을 수 있는 높이
+pub const HEADER_HEIGHT: f32 = 2.5; // 헤더로 공을 걷어내거나 연결할 수 있는 높이
+pub const GOALKEEPER_HANDLING_HEIGHT: f32 = 2.6; // 골키퍼가 손으로 잡을 수 있는 높이
+pub const LOFT_ANGLE: f32 = 0.6; // 로빙 패스 발사 각도 (rad)
+pub const POSSESSION_RANGE: f32 = 1.5; // 공 소유 판정 거리
+pub const SHOT_SPEED: f32 = 25.0; // 슈팅 속도 (m/s)
+pub const MIN_PASS_DISTANCE: f32 = 5.0; // 최소 패스 거리
+pub const GRID_CELL_SIZE: f32 = 5.0; // 공간 격자 한 칸 크기
+const GRID_MARGIN: f32 = 5.0; // 경기장 밖으로 격자를 확장하는 여유
 
-/// 이동 계산
-pub fn move_towards(position: Vec2, target: Vec2, max_speed: f32, delta_time: f32) -> Vec2 {
-    let direction = Vec2::new(target.x - position.x, target.y - position.y);
-    let distance = direction.length();
+/// 선수 이동 한계 (가속, 감속, 회전)
+#[derive(Debug, Clone, Copy)]
+pub struct MovementLimits {
+    /// 최고 속도 (m/s)
+    pub max_speed: f32,
+    /// 가속도 (m/s²)
+    pub acceleration: f32,
+    /// 감속도 (m/s²)
+    pub deceleration: f32,
+    /// 최대 회전 속도 (rad/s)
+    pub turn_rate: f32,
+}
+
+/// 이 속도 이하에서는 제자리에서 방향을 바로 바꿀 수 있음 (m/s)
+const STANDING_TURN_SPEED: f32 = 0.5;
+
+/// 속도를 가진 선수를 목표 지점으로 조향
+///
+/// 목표 방향으로 최대 회전 속도만큼만 방향을 틀고, 가속/감속 한계 안에서 속도를 바꾼다.
+/// 목표에 가까워지면 감속도로 멈출 수 있는 속도까지 줄이고, 급격한 방향 전환 시에도 속도를 줄인다.
+/// 새 위치와 새 속도를 반환
+pub fn steer_towards(
+    position: Vec2,
+    velocity: Vec2,
+    target: Vec2,
+    limits: &MovementLimits,
+    delta_time: f32,
+) -> (Vec2, Vec2) {
+    let to_target = Vec2::new(target.x - position.x, target.y - position.y);
+    let distance = to_target.length();
+    let speed = velocity.length();
     
-    if distance < 0.01 {
-        return position;
+    let desired_direction = if distance > 0.01 { to_target.normalize() } else { velocity.normalize() };
+    let heading = if speed > STANDING_TURN_SPEED { velocity.normalize() } else { desired_direction };
+    
+    // 최대 회전 속도 안에서 방향 전환
+    let current_angle = heading.y.atan2(heading.x);
+    let desired_angle = desired_direction.y.atan2(desired_direction.x);
+    let mut turn = desired_angle - current_angle;
+    while turn > std::f32::consts::PI {
+        turn -= std::f32::consts::TAU;
     }
+    while turn < -std::f32::consts::PI {
+        turn += std::f32::consts::TAU;
+    }
+    let max_turn = limits.turn_rate * delta_time;
+    let new_angle = current_angle + turn.clamp(-max_turn, max_turn);
+    let new_heading = Vec2::new(new_angle.cos(), new_angle.sin());
     
-    let normalized = direction.normalize();
-    let move_distance = (max_speed * delta_time).min(distance);
+    // 도착 지점에서 멈출 수 있는 속도, 등 뒤로 돌아야 하면 속도를 줄임
+    let stopping_speed = (2.0 * limits.deceleration * distance).sqrt();
+    let alignment = turn.cos().max(0.2);
+    let desired_speed = if distance > 0.01 {
+        limits.max_speed.min(stopping_speed) * alignment
+    } else {
+        0.0
+    };
+    let new_speed = if desired_speed > speed {
+        (speed + limits.acceleration * delta_time).min(desired_speed)
+    } else {
+        (speed - limits.deceleration * delta_time).max(desired_speed)
+    };
     
-    Vec2::new(
-        position.x + normalized.x * move_distance,
-        position.y + normalized.y * move_distance,
-    )
+    let new_velocity = Vec2::new(new_heading.x * new_speed, new_heading.y * new_speed);
+    let mut new_position = Vec2::new(
+        position.x + new_velocity.x * delta_time,
+        position.y + new_velocity.y * delta_time,
+    );
+    // 목표를 지나치지 않음
+    if distance > 0.01 && position.distance(&new_position) > distance && turn.abs() < 0.01 {
+        new_position = target;
+    }
+    (new_position, new_velocity)
+}
+
+/// 균일 공간 격자 (경기장을 일정 크기 칸으로 나눈 공간 해시)
+///
+/// 위치를 인덱스와 함께 칸에 넣어 두고, 반경/선분 주변 칸만 훑어 근처 항목을 찾음.
+/// 경기장 밖 위치는 가장자리 칸에 들어가므로 검색 결과는 전수 탐색과 같음
+#[derive(Debug, Clone)]
+pub struct SpatialGrid {
+    cell_size: f32,
+    columns: usize,
+    rows: usize,
+    cells: Vec<Vec<(usize, Vec2)>>,
+    /// 항목 인덱스별 팀 (선수 목록으로 만든 격자에서만 채움)
+    teams: Vec<u8>,
+}
+
+impl SpatialGrid {
+    pub fn new(pitch: &Pitch, cell_size: f32) -> Self {
+        let columns = ((pitch.width + 2.0 * GRID_MARGIN) / cell_size).ceil() as usize;
+        let rows = ((pitch.length + 2.0 * GRID_MARGIN) / cell_size).ceil() as usize;
+        Self {
+            cell_size,
+            columns,
+            rows,
+            cells: vec![Vec::new(); columns * rows],
+            teams: Vec::new(),
+        }
+    }
+
+    /// 위치 목록으로 격자 생성 (인덱스는 목록 순서)
+    pub fn from_positions(pitch: &Pitch, positions: impl IntoIterator<Item = Vec2>) -> Self {
+        let mut grid = Self::new(pitch, GRID_CELL_SIZE);
+        for (index, position) in positions.into_iter().enumerate() {
+            grid.insert(index, position);
+        }
+        grid
+    }
+
+    /// 선수 목록으로 격자 생성 (인덱스는 목록 순서, 팀별 검색 가능)
+    pub fn from_players(pitch: &Pitch, players: &[Player]) -> Self {
+        let mut grid = Self::new(pitch, GRID_CELL_SIZE);
+        grid.refresh(players);
+        grid
+    }
+
+    /// 선수들이 움직인 뒤 칸 메모리를 유지한 채 다시 채움
+    pub fn refresh(&mut self, players: &[Player]) {
+        self.clear();
+        for (index, player) in players.iter().enumerate() {
+            self.insert(index, player.position);
+            self.teams.push(player.team_id);
+        }
+    }
+
+    /// 한 팀 선수만 검색하는 격자 보기
+    pub fn team(&self, team_id: u8) -> TeamGrid<'_> {
+        TeamGrid { grid: self, team_id }
+    }
+
+    pub fn insert(&mut self, index: usize, position: Vec2) {
+        let cell = self.cell_index(self.column(position.x), self.row(position.y));
+        self.cells[cell].push((index, position));
+    }
+
+    /// 칸 메모리는 유지한 채 모든 항목 제거
+    pub fn clear(&mut self) {
+        for cell in &mut self.cells {
+            cell.clear();
+        }
+        self.teams.clear();
+    }
+
+    /// 중심에서 반경 안의 항목 (인덱스, 위치)
+    pub fn within(&self, center: Vec2, radius: f32) -> impl Iterator<Item = (usize, Vec2)> + '_ {
+        let min = Vec2::new(center.x - radius, center.y - radius);
+        let max = Vec2::new(center.x + radius, center.y + radius);
+        self.in_box(min, max)
+            .filter(move |(_, position)| center.distance(position) < radius)
+    }
+
+    /// 선분에서 거리 안의 항목 (인덱스, 위치)
+    pub fn near_segment(&self, start: Vec2, end: Vec2, radius: f32) -> impl Iterator<Item = (usize, Vec2)> + '_ {
+        let min = Vec2::new(start.x.min(end.x) - radius, start.y.min(end.y) - radius);
+        let max = Vec2::new(start.x.max(end.x) + radius, start.y.max(end.y) + radius);
+        self.in_box(min, max)
+            .filter(move |(_, position)| distance_to_segment(position, &start, &end) < radius)
+    }
+
+    /// 사각 영역에 걸친 칸들의 모든 항목 (정확한 거리 판정 전 후보)
+    fn in_box(&self, min: Vec2, max: Vec2) -> impl Iterator<Item = (usize, Vec2)> + '_ {
+        let (min_column, max_column) = (self.column(min.x), self.column(max.x));
+        let (min_row, max_row) = (self.row(min.y), self.row(max.y));
+        (min_row..=max_row).flat_map(move |row| {
+            (min_column..=max_column).flat_map(move |column| {
+                self.cells[self.cell_index(column, row)].iter().copied()
+            })
+        })
+    }
+
+    fn column(&self, x: f32) -> usize {
+        (((x + GRID_MARGIN) / self.cell_size).floor().max(0.0) as usize).min(self.columns - 1)
+    }
+
+    fn row(&self, y: f32) -> usize {
+        (((y + GRID_MARGIN) / self.cell_size).floor().max(0.0) as usize).min(self.rows - 1)
+    }
+
+    fn cell_index(&self, column: usize, row: usize) -> usize {
+        row * self.columns + column
+    }
+}
+
+/// 선수 격자에서 한 팀만 걸러 보는 보기 (상대 선수 검색용)
+#[derive(Debug, Clone, Copy)]
+pub struct TeamGrid<'a> {
+    grid: &'a SpatialGrid,
+    team_id: u8,
+}
+
+impl<'a> TeamGrid<'a> {
+    /// 중심에서 반경 안의 팀 선수 (선수 인덱스, 위치)
+    pub fn within(self, center: Vec2, radius: f32) -> impl Iterator<Item = (usize, Vec2)> + 'a {
+        self.grid.within(center, radius).filter(move |(index, _)| self.contains(*index))
+    }
+
+    /// 선분에서 거리 안의 팀 선수 (선수 인덱스, 위치)
+    pub fn near_segment(self, start: Vec2, end: Vec2, radius: f32) -> impl Iterator<Item = (usize, Vec2)> + 'a {
+        self.grid.near_segment(start, end, radius).filter(move |(index, _)| self.contains(*index))
+    }
+
+    fn contains(&self, index: usize) -> bool {
+        self.grid.teams.get(index) == Some(&self.team_id)
+    }
 }
 
 /// 충돌 회피
+///
+/// 주변 위치는 보통 `SpatialGrid::within`으로 최소 거리 안의 것만 넘김
 pub fn avoid_collision(
     position: Vec2,
-    other_positions: &[Vec2],
+    other_positions: impl IntoIterator<Item = Vec2>,
     min_distance: f32,
 ) -> Vec2 {
     let mut avoidance = Vec2::new(0.0, 0.0);
     let mut count = 0;
     
     for other in other_positions {
-        let distance = position.distance(other);
+        let distance = position.distance(&other);
         if distance < min_distance && distance > 0.01 {
             let direction = Vec2::new(
                 position.x - other.x,
@@ -62,36 +270,401 @@ pub fn avoid_collision(
 }
 
 /// 공 소유권 체크
-pub fn check_ball_ownership(
+///
+/// 현재 소유자가 범위 안에 있으면 유지하고, 루즈볼은 범위 안 선수들 중
+/// 공에 가까울수록 높은 확률로 소유권을 얻음
+///
+/// `grid`는 `players` 순서대로 인덱스를 매긴 선수 위치 격자
+pub fn check_ball_ownership<R: Rng>(
     ball: &Ball,
     players: &[Player],
+    grid: &SpatialGrid,
     possession_range: f32,
+    excluded: Option<u32>,
+    rng: &mut R,
 ) -> Option<u32> {
-    for player in players {
-        let distance = ball.position.distance(&player.position);
-        if distance < possession_range {
-            return Some(player.id);
+    // 발/가슴 높이보다 높이 뜬 공은 잡을 수 없음
+    if ball.height > CONTROL_HEIGHT {
+        return None;
+    }
+    
+    // 현재 소유자가 범위 안에 있으면 소유 유지
+    if let Some(owner) = ball.owner.and_then(|id| players.iter().find(|p| p.id == id)) {
+        if ball.position.distance(&owner.position) < possession_range {
+            return Some(owner.id);
+        }
+    }
+    
+    // 방금 공을 찬 선수는 즉시 다시 소유할 수 없음
+    // 가깝고 빠른 선수일수록 루즈볼을 따낼 확률이 높음
+    let mut nearby: Vec<usize> = grid.within(ball.position, possession_range)
+        .map(|(index, _)| index)
+        .collect();
+    nearby.sort_unstable();
+    let contenders: Vec<(u32, f32)> = nearby.into_iter()
+        .map(|index| &players[index])
+        .filter(|player| excluded != Some(player.id))
+        .map(|player| (player, ball.position.distance(&player.position)))
+        .map(|(player, distance)| (player.id, loose_ball_weight(player, distance, possession_range)))
+        .collect();
+    
+    let total_weight: f32 = contenders.iter().map(|(_, weight)| weight).sum();
+    if contenders.len() <= 1 || total_weight <= 0.0 {
+        return contenders.first().map(|(id, _)| *id);
+    }
+    
+    let mut roll = rng.gen::<f32>() * total_weight;
+    for (id, weight) in &contenders {
+        if roll < *weight {
+            return Some(*id);
         }
+        roll -= weight;
     }
-    None
+    contenders.last().map(|(id, _)| *id)
+}
+
+/// 간접 프리킥 위치 (골 에어리어 안이면 골 에어리어 라인으로 이동)
+pub fn indirect_free_kick_spot(pitch: &Pitch, goal: &Goal, location: Vec2) -> Vec2 {
+    let mut spot = location;
+    if (spot.y - goal.center.y).abs() < pitch.goal_area_depth {
+        spot.y = goal.center.y + goal.inward() * pitch.goal_area_depth;
+    }
+    spot
+}
+
+/// 패스 순간 리시버가 오프사이드 위치에 있는지 판정
+///
+/// 상대 진영에서 공과 뒤에서 두 번째 수비수(골키퍼 포함)보다 골라인에 가까우면 오프사이드.
+/// 같은 선상은 온사이드
+pub fn is_offside_position(pitch: &Pitch, receiver: &Vec2, ball: &Vec2, defenders: &[Vec2], goal: &Goal) -> bool {
+    let depth = |pos: &Vec2| (pos.y - goal.center.y).abs();
+    let receiver_depth = depth(receiver);
+    
+    // 자기 진영에서는 오프사이드가 아님
+    if receiver_depth >= pitch.length / 2.0 {
+        return false;
+    }
+    if receiver_depth >= depth(ball) {
+        return false;
+    }
+    
+    receiver_depth < defensive_line_depth(defenders, goal)
+}
+
+/// 수비 라인 (뒤에서 두 번째 수비수, 골키퍼 포함)의 골라인으로부터 거리
+///
+/// 수비수가 두 명 미만이면 골라인이 두 번째 수비수 역할
+pub fn defensive_line_depth(defenders: &[Vec2], goal: &Goal) -> f32 {
+    let mut defender_depths: Vec<f32> = defenders.iter().map(|pos| (pos.y - goal.center.y).abs()).collect();
+    defender_depths.sort_by(|a, b| a.total_cmp(b));
+    defender_depths.get(1).copied().unwrap_or(0.0)
+}
+
+/// y = 0 쪽 골대를 수비하는 팀 (전반에는 홈 팀, 진영 교체 후에는 어웨이 팀)
+pub fn team_defending_low_end(ends_swapped: bool) -> u8 {
+    if ends_swapped { 1 } else { 0 }
+}
+
+/// 팀이 수비하는 골대 (전반 기준 홈 팀은 y = 0, 어웨이 팀은 y = pitch.length)
+pub fn defended_goal(pitch: &Pitch, team_id: u8, ends_swapped: bool) -> Goal {
+    let line_y = if team_id == team_defending_low_end(ends_swapped) { 0.0 } else { pitch.length };
+    Goal::new(Vec2::new(pitch.width / 2.0, line_y), pitch.goal_width)
+}
+
+/// 공이 골포스트 사이로 골라인을 넘었는지 검사
+///
+/// 골이면 실점한 팀 ID 반환
+pub fn check_goal(pitch: &Pitch, ball: &Ball, ends_swapped: bool) -> Option<u8> {
+    let low_team = team_defending_low_end(ends_swapped);
+    let conceding_team = if ball.position.y < 0.0 {
+        low_team
+    } else if ball.position.y > pitch.length {
+        1 - low_team
+    } else {
+        return None;
+    };
+    // 크로스바 위로 넘어가면 골이 아님
+    (defended_goal(pitch, conceding_team, ends_swapped).is_between_posts(ball.position.x) && ball.height < pitch.goal_height)
+        .then_some(conceding_team)
+}
+
+/// 위치가 골대 앞 페널티 박스 안인지
+pub fn in_penalty_area(pitch: &Pitch, goal: &Goal, position: &Vec2) -> bool {
+    let depth = (position.y - goal.center.y) * goal.inward();
+    (position.x - goal.center.x).abs() <= pitch.penalty_area_width / 2.0
+        && (0.0..=pitch.penalty_area_depth).contains(&depth)
+}
+
+/// 위치를 페널티 박스 안으로 제한
+pub fn clamp_to_penalty_area(pitch: &Pitch, goal: &Goal, position: Vec2) -> Vec2 {
+    let half_width = pitch.penalty_area_width / 2.0;
+    let depth = ((position.y - goal.center.y) * goal.inward()).clamp(0.0, pitch.penalty_area_depth);
+    Vec2::new(
+        position.x.clamp(goal.center.x - half_width, goal.center.x + half_width),
+        goal.center.y + depth * goal.inward(),
+    )
+}
+
+/// 골키퍼 위치: 공과 골문 중심을 잇는 선 위, 공이 멀수록 골문에서 앞으로 나옴
+pub fn goalkeeper_position(goal: &Goal, ball_position: &Vec2) -> Vec2 {
+    let to_ball = Vec2::new(
+        ball_position.x - goal.center.x,
+        ball_position.y - goal.center.y,
+    );
+    let distance = to_ball.length();
+    if distance < 0.01 {
+        return goal.center;
+    }
+    let direction = to_ball.normalize();
+    let step_out = (distance * 0.15).clamp(1.0, 6.0);
+    // 공이 골라인 뒤쪽에 있어도 골문 앞에 머무름
+    let depth = (direction.y * goal.inward()).max(0.3);
+    Vec2::new(
+        goal.center.x + direction.x * step_out,
+        goal.center.y + depth * step_out * goal.inward(),
+    )
+}
+
+/// 공이 경기장을 벗어난 경계
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BoundaryExit {
+    /// 터치라인 (x = 0 또는 pitch.width)
+    Touchline,
+    /// 골라인 (골문 바깥), 해당 골라인을 수비하는 팀
+    GoalLine { defending_team: u8 },
+}
+
+/// 공이 경기장 밖으로 나갔는지 검사 (골은 check_goal에서 먼저 처리)
+pub fn check_out_of_play(pitch: &Pitch, ball: &Ball, ends_swapped: bool) -> Option<BoundaryExit> {
+    let low_team = team_defending_low_end(ends_swapped);
+    if ball.position.y < 0.0 {
+        Some(BoundaryExit::GoalLine { defending_team: low_team })
+    } else if ball.position.y > pitch.length {
+        Some(BoundaryExit::GoalLine { defending_team: 1 - low_team })
+    } else if ball.position.x < 0.0 || ball.position.x > pitch.width {
+        Some(BoundaryExit::Touchline)
+    } else {
+        None
+    }
+}
+
+/// 목표 지점까지 굴러가기 위한 패스 속도 (m/s)
+///
+/// 구름 감쇠를 고려하여 약간 여유 있게(20%) 도달하도록 계산
+pub fn pass_speed_for_distance(distance: f32) -> f32 {
+    (distance * BALL_ROLLING_DRAG * 1.2).clamp(8.0, 30.0)
+}
+
+/// 목표 지점 근처에 떨어지는 로빙 패스의 (수평 속도, 수직 속도)
+///
+/// 공기 저항을 무시한 포물선 사거리로 계산하며, 떨어진 뒤 굴러서 목표에 닿도록
+/// 거리의 85% 지점에 착지시킴
+pub fn lofted_pass_velocity(distance: f32) -> (f32, f32) {
+    let carry = distance * 0.85;
+    let speed = (carry * GRAVITY / (2.0 * LOFT_ANGLE).sin()).sqrt();
+    (speed * LOFT_ANGLE.cos(), speed * LOFT_ANGLE.sin())
+}
+
+/// 공 차기 (소유권 해제 후 목표 방향으로 땅볼 속도 부여)
+pub fn kick_ball(ball: &mut Ball, target: Vec2, speed: f32) {
+    loft_ball(ball, target, speed, 0.0);
+}
+
+/// 공 띄워 차기 (소유권 해제 후 수평/수직 속도 부여)
+pub fn loft_ball(ball: &mut Ball, target: Vec2, horizontal_speed: f32, vertical_speed: f32) {
+    let direction = Vec2::new(
+        target.x - ball.position.x,
+        target.y - ball.position.y,
+    ).normalize();
+    ball.velocity = Vec2::new(direction.x * horizontal_speed, direction.y * horizontal_speed);
+    ball.vertical_velocity = vertical_speed;
+    ball.owner = None;
 }
 
 /// 공 이동 (물리 업데이트)
-pub fn update_ball(
-    ball: &mut Ball,
-    delta_time: f32,
-    friction: f32,
-) {
-    // 속도 감쇠 (마찰)
-    ball.velocity.x *= 1.0 - (friction * delta_time);
-    ball.velocity.y *= 1.0 - (friction * delta_time);
+///
+/// 공중에서는 중력과 공기 저항, 지면에서는 구름 저항을 받으며,
+/// 땅에 닿으면 반발 계수만큼 튀어 오름
+pub fn update_ball(ball: &mut Ball, delta_time: f32) {
+    if ball.is_airborne() {
+        ball.velocity.x *= 1.0 - BALL_AIR_DRAG * delta_time;
+        ball.velocity.y *= 1.0 - BALL_AIR_DRAG * delta_time;
+        ball.vertical_velocity -= GRAVITY * delta_time;
+        ball.height += ball.vertical_velocity * delta_time;
+        
+        if ball.height <= 0.0 {
+            // 바운드: 충분히 느리면 굴러가기 시작
+            ball.height = 0.0;
+            ball.vertical_velocity = -ball.vertical_velocity * BALL_RESTITUTION;
+            if ball.vertical_velocity < 1.0 {
+                ball.vertical_velocity = 0.0;
+            }
+            ball.velocity.x *= BALL_BOUNCE_FRICTION;
+            ball.velocity.y *= BALL_BOUNCE_FRICTION;
+        }
+    } else {
+        ball.velocity.x *= 1.0 - BALL_ROLLING_DRAG * delta_time;
+        ball.velocity.y *= 1.0 - BALL_ROLLING_DRAG * delta_time;
+        let speed = ball.velocity.length();
+        if speed > 0.0 {
+            let slowed = (speed - BALL_ROLLING_RESISTANCE * delta_time).max(0.0);
+            ball.velocity.x *= slowed / speed;
+            ball.velocity.y *= slowed / speed;
+        }
+    }
     
     // 위치 업데이트
     ball.position.x += ball.velocity.x * delta_time;
     ball.position.y += ball.velocity.y * delta_time;
-    
-    // 경기장 경계 체크
-    ball.position.x = ball.position.x.max(0.0).min(FIELD_WIDTH);
-    ball.position.y = ball.position.y.max(0.0).min(FIELD_HEIGHT);
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+
+    const LIMITS: MovementLimits = MovementLimits {
+        max_speed: 6.0,
+        acceleration: 4.0,
+        deceleration: 6.0,
+        turn_rate: 4.0,
+    };
+
+    #[test]
+    fn players_accelerate_instead_of_jumping_to_top_speed() {
+        let start = Vec2::new(0.0, 0.0);
+        let target = Vec2::new(0.0, 50.0);
+        let (position, velocity) = steer_towards(start, Vec2::default(), target, &LIMITS, 0.1);
+        assert!((velocity.length() - 0.4).abs() < 1e-4);
+        assert!(position.y > 0.0 && position.y < 0.1);
+
+        let mut state = (position, velocity);
+        for _ in 0..30 {
+            state = steer_towards(state.0, state.1, target, &LIMITS, 0.1);
+        }
+        assert!((state.1.length() - LIMITS.max_speed).abs() < 1e-4);
+    }
+
+    #[test]
+    fn running_players_turn_at_a_limited_rate() {
+        // +y 방향으로 달리다가 -y 방향 목표로 방향 전환
+        let velocity = Vec2::new(0.0, 6.0);
+        let (_, turned) = steer_towards(Vec2::new(0.0, 10.0), velocity, Vec2::new(0.0, 0.0), &LIMITS, 0.1);
+        let turned_angle = (velocity.x * turned.x + velocity.y * turned.y) / (velocity.length() * turned.length());
+        assert!((turned_angle.acos() - 0.4).abs() < 1e-3);
+        assert!(turned.length() < velocity.length());
+    }
+
+    #[test]
+    fn lofted_ball_lands_near_target_and_bounces_lower() {
+        let mut ball = Ball::new(0.0, 0.0);
+        let (horizontal, vertical) = lofted_pass_velocity(30.0);
+        loft_ball(&mut ball, Vec2::new(0.0, 30.0), horizontal, vertical);
+
+        let mut peak = 0.0f32;
+        let mut first_landing = None;
+        let mut second_peak = 0.0f32;
+        for _ in 0..200 {
+            update_ball(&mut ball, 0.01);
+            if first_landing.is_none() {
+                peak = peak.max(ball.height);
+                if ball.height == 0.0 {
+                    first_landing = Some(ball.position.y);
+                }
+            } else {
+                second_peak = second_peak.max(ball.height);
+            }
+        }
+        let landing = first_landing.expect("ball should land");
+        assert!(landing > 20.0 && landing < 30.0);
+        assert!(second_peak > 0.0 && second_peak < peak);
+    }
+
+    #[test]
+    fn high_balls_cannot_be_controlled() {
+        let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+        let mut ball = Ball::new(10.0, 10.0);
+        let player = Player {
+            id: 3,
+            team_id: 0,
+            role: "Player".to_string(),
+            position: Vec2::new(10.5, 10.0),
+            velocity: Vec2::default(),
+            stamina: 1.0,
+            morale: 0.7,
+            has_ball: false,
+            persona: Default::default(),
+        };
+        let players = std::slice::from_ref(&player);
+        let grid = SpatialGrid::from_positions(&Pitch::standard(), [player.position]);
+        ball.height = CONTROL_HEIGHT + 0.5;
+        assert_eq!(check_ball_ownership(&ball, players, &grid, POSSESSION_RANGE, None, &mut rng), None);
+        ball.height = 0.3;
+        assert_eq!(check_ball_ownership(&ball, players, &grid, POSSESSION_RANGE, None, &mut rng), Some(3));
+    }
+
+    #[test]
+    fn spatial_grid_matches_brute_force_search() {
+        let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(7);
+        // 경기장 밖 위치도 포함
+        let positions: Vec<Vec2> = (0..44)
+            .map(|_| Vec2::new(rng.gen_range(-8.0..76.0), rng.gen_range(-8.0..113.0)))
+            .collect();
+        let grid = SpatialGrid::from_positions(&Pitch::standard(), positions.iter().copied());
+        for center in &positions {
+            for radius in [1.0, 5.0, 12.0] {
+                let mut found: Vec<usize> = grid.within(*center, radius).map(|(i, _)| i).collect();
+                found.sort_unstable();
+                let expected: Vec<usize> = (0..positions.len())
+                    .filter(|&i| center.distance(&positions[i]) < radius)
+                    .collect();
+                assert_eq!(found, expected);
+            }
+        }
+        let (start, end) = (Vec2::new(5.0, 10.0), Vec2::new(60.0, 90.0));
+        let mut found: Vec<usize> = grid.near_segment(start, end, 2.0).map(|(i, _)| i).collect();
+        found.sort_unstable();
+        let expected: Vec<usize> = (0..positions.len())
+            .filter(|&i| distance_to_segment(&positions[i], &start, &end) < 2.0)
+            .collect();
+        assert_eq!(found, expected);
+
+        // 선수 격자의 팀 보기는 그 팀 선수만 돌려줌
+        let players: Vec<Player> = positions.iter().enumerate()
+            .map(|(i, position)| Player {
+                id: i as u32,
+                team_id: (i % 2) as u8,
+                role: "Player".to_string(),
+                position: *position,
+                velocity: Vec2::default(),
+                stamina: 1.0,
+                morale: 0.7,
+                has_ball: false,
+                persona: Default::default(),
+            })
+            .collect();
+        let grid = SpatialGrid::from_players(&Pitch::standard(), &players);
+        let mut found: Vec<usize> = grid.team(1).near_segment(start, end, 2.0).map(|(i, _)| i).collect();
+        found.sort_unstable();
+        assert_eq!(found, expected.iter().copied().filter(|i| i % 2 == 1).collect::<Vec<usize>>());
+        assert!(grid.team(0).within(positions[0], 12.0).all(|(i, _)| i % 2 == 0));
+    }
+
+    #[test]
+    fn offside_is_judged_against_second_last_defender() {
+        // y = 0 골문을 공격하는 상황
+        let pitch = Pitch::standard();
+        let goal = defended_goal(&pitch, 0, false);
+        let ball = Vec2::new(34.0, 40.0);
+        let defenders = [Vec2::new(34.0, 2.0), Vec2::new(30.0, 20.0), Vec2::new(40.0, 30.0)];
+
+        assert!(is_offside_position(&pitch, &Vec2::new(34.0, 15.0), &ball, &defenders, &goal));
+        // 두 번째 수비수와 같은 선상은 온사이드
+        assert!(!is_offside_position(&pitch, &Vec2::new(34.0, 20.0), &ball, &defenders, &goal));
+        // 공보다 뒤에 있으면 온사이드
+        assert!(!is_offside_position(&pitch, &Vec2::new(34.0, 15.0), &Vec2::new(34.0, 12.0), &defenders, &goal));
+        // 자기 진영에서는 오프사이드 없음
+        let deep_line = [Vec2::new(34.0, 60.0), Vec2::new(30.0, 65.0)];
+        assert!(!is_offside_position(&pitch, &Vec2::new(34.0, 55.0), &Vec2::new(34.0, 80.0), &deep_line, &goal));
+    }
+}
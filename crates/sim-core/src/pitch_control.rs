@@ -0,0 +1,275 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use crate::config::Pitch;
+use crate::stamina::performance_factor;
+use crate::triggers::to_team_view;
+use crate::types::{Player, Vec2};
+
+/// 격자 칸의 목표 크기 (m, 경기장 크기에 맞춰 조정됨)
+pub const CELL_SIZE: f32 = 3.0;
+/// 열린 구역으로 보는 팀 통제 확률
+pub const OPEN_ZONE_CONTROL: f32 = 0.6;
+/// 선수가 현재 속도를 유지한 채 반응하기까지의 시간 (s)
+const REACTION_TIME: f32 = 0.7;
+/// 도달 시간 계산에 쓰는 최고 속도 (m/s, 체력에 따라 감소)
+const REACH_SPEED: f32 = 7.0;
+/// 도달 시간 차이에 대한 통제 확률의 민감도 (s, 작을수록 먼저 도착하는 선수가 독점)
+const CONTROL_TEMPERATURE: f32 = 0.5;
+/// 상대가 완전히 통제하는 패스 길 한 칸에서 공을 잃을 확률
+const LANE_INTERCEPTION: f32 = 0.15;
+
+/// 선수가 목표 지점에 도달하는 데 걸리는 시간 (s)
+///
+/// 반응 시간 동안은 현재 속도로 움직이고, 그 뒤 최고 속도로 직선 이동
+pub fn time_to_reach(player: &Player, target: &Vec2) -> f32 {
+    let projected = Vec2::new(
+        player.position.x + player.velocity.x * REACTION_TIME,
+        player.position.y + player.velocity.y * REACTION_TIME,
+    );
+    REACTION_TIME + projected.distance(target) / (REACH_SPEED * performance_factor(player.stamina))
+}
+
+/// 팀 관점의 세로 3등분 (자기 진영 → 상대 진영)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Third {
+    Defensive,
+    Middle,
+    Attacking,
+}
+
+impl fmt::Display for Third {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Third::Defensive => write!(f, "defensive"),
+            Third::Middle => write!(f, "middle"),
+            Third::Attacking => write!(f, "attacking"),
+        }
+    }
+}
+
+/// 팀 관점의 가로 3등분 (상대 골문을 바라볼 때 왼쪽 → 오른쪽)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Channel {
+    Left,
+    Centre,
+    Right,
+}
+
+impl fmt::Display for Channel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Channel::Left => write!(f, "left"),
+            Channel::Centre => write!(f, "centre"),
+            Channel::Right => write!(f, "right"),
+        }
+    }
+}
+
+/// 팀 관점 3x3 구역의 평균 통제 확률
+#[derive(Debug, Clone, Copy, Serialize)]
+pub struct ZoneControl {
+    pub third: Third,
+    pub channel: Channel,
+    /// 구역 중심 (경기장 좌표)
+    pub center: Vec2,
+    pub control: f32,
+}
+
+/// 경기장 격자 칸마다 각 팀이 공을 먼저 차지할 확률
+///
+/// 칸 중심까지의 도달 시간에 소프트맥스를 적용해 가장 먼저 닿는 선수의 팀이 통제
+#[derive(Debug, Clone)]
+pub struct PitchControl {
+    pub columns: usize,
+    pub rows: usize,
+    pub cell_width: f32,
+    pub cell_length: f32,
+    /// 칸마다 홈 팀(0)의 통제 확률 (행 우선, 어웨이 팀은 1에서 뺀 값)
+    home: Vec<f32>,
+}
+
+impl PitchControl {
+    pub fn compute(pitch: &Pitch, players: &[Player]) -> Self {
+        let columns = (pitch.width / CELL_SIZE).ceil().max(1.0) as usize;
+        let rows = (pitch.length / CELL_SIZE).ceil().max(1.0) as usize;
+        let mut control = Self {
+            columns,
+            rows,
+            cell_width: pitch.width / columns as f32,
+            cell_length: pitch.length / rows as f32,
+            home: Vec::with_capacity(columns * rows),
+        };
+        for row in 0..rows {
+            for column in 0..columns {
+                let center = control.cell_center(column, row);
+                let times: Vec<(u8, f32)> = players.iter()
+                    .map(|p| (p.team_id, time_to_reach(p, &center)))
+                    .collect();
+                let fastest = times.iter().map(|(_, t)| *t).fold(f32::INFINITY, f32::min);
+                let (mut home, mut total) = (0.0, 0.0);
+                for (team_id, t) in times {
+                    let weight = (-(t - fastest) / CONTROL_TEMPERATURE).exp();
+                    total += weight;
+                    if team_id == 0 {
+                        home += weight;
+                    }
+                }
+                control.home.push(if total > 0.0 { home / total } else { 0.5 });
+            }
+        }
+        control
+    }
+
+    pub fn cell_center(&self, column: usize, row: usize) -> Vec2 {
+        Vec2::new(
+            (column as f32 + 0.5) * self.cell_width,
+            (row as f32 + 0.5) * self.cell_length,
+        )
+    }
+
+    /// 칸 중심과 홈 팀 통제 확률 (오버레이 표시용)
+    pub fn cells(&self) -> impl Iterator<Item = (Vec2, f32)> + '_ {
+        self.home.iter().enumerate().map(|(index, home)| {
+            (self.cell_center(index % self.columns, index / self.columns), *home)
+        })
+    }
+
+    /// 위치가 속한 칸의 팀 통제 확률
+    pub fn control_at(&self, position: &Vec2, team_id: u8) -> f32 {
+        let column = ((position.x / self.cell_width).max(0.0) as usize).min(self.columns - 1);
+        let row = ((position.y / self.cell_length).max(0.0) as usize).min(self.rows - 1);
+        let home = self.home[row * self.columns + column];
+        if team_id == 0 { home } else { 1.0 - home }
+    }
+
+    /// 패스 성공 확률
+    ///
+    /// 받는 지점의 팀 통제 확률에, 패스 길의 칸마다 상대 통제만큼 공을 잃을 위험을 곱함
+    pub fn pass_success_probability(&self, from: &Vec2, to: &Vec2, team_id: u8) -> f32 {
+        let distance = from.distance(to);
+        let steps = (distance / self.cell_width.min(self.cell_length)).ceil() as usize;
+        let lane: f32 = (1..steps)
+            .map(|step| {
+                let t = step as f32 / steps as f32;
+                let point = Vec2::new(from.x + (to.x - from.x) * t, from.y + (to.y - from.y) * t);
+                1.0 - LANE_INTERCEPTION * (1.0 - self.control_at(&point, team_id))
+            })
+            .product();
+        self.control_at(to, team_id) * lane
+    }
+
+    /// 팀 관점 3x3 구역별 평균 통제 확률 (자기 진영 → 상대 진영, 왼쪽 → 오른쪽 순)
+    pub fn zone_control(&self, pitch: &Pitch, team_id: u8, defends_low_end: bool) -> Vec<ZoneControl> {
+        const THIRDS: [Third; 3] = [Third::Defensive, Third::Middle, Third::Attacking];
+        const CHANNELS: [Channel; 3] = [Channel::Left, Channel::Centre, Channel::Right];
+        let mut sums = [[(0.0f32, Vec2::default(), 0usize); 3]; 3];
+        for (center, home) in self.cells() {
+            let view = to_team_view(pitch, &center, defends_low_end);
+            let third = ((view.y * 3.0) as usize).min(2);
+            let channel = ((view.x * 3.0) as usize).min(2);
+            let zone = &mut sums[third][channel];
+            zone.0 += if team_id == 0 { home } else { 1.0 - home };
+            zone.1 = Vec2::new(zone.1.x + center.x, zone.1.y + center.y);
+            zone.2 += 1;
+        }
+        let mut zones = Vec::with_capacity(9);
+        for (third, row) in sums.iter().enumerate() {
+            for (channel, (sum, centers, count)) in row.iter().enumerate() {
+                let count = (*count).max(1) as f32;
+                zones.push(ZoneControl {
+                    third: THIRDS[third],
+                    channel: CHANNELS[channel],
+                    center: Vec2::new(centers.x / count, centers.y / count),
+                    control: sum / count,
+                });
+            }
+        }
+        zones
+    }
+
+    /// 팀이 통제하는 자기 진영 밖의 열린 구역 (통제 확률 높은 순)
+    pub fn open_zones(&self, pitch: &Pitch, team_id: u8, defends_low_end: bool) -> Vec<ZoneControl> {
+        let mut zones: Vec<ZoneControl> = self.zone_control(pitch, team_id, defends_low_end)
+            .into_iter()
+            .filter(|zone| zone.third != Third::Defensive && zone.control >= OPEN_ZONE_CONTROL)
+            .collect();
+        zones.sort_by(|a, b| b.control.total_cmp(&a.control));
+        zones
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::Persona;
+
+    fn player(id: u32, team_id: u8, x: f32, y: f32) -> Player {
+        Player {
+            id,
+            team_id,
+            role: "Midfielder".to_string(),
+            position: Vec2::new(x, y),
+            velocity: Vec2::default(),
+            stamina: 1.0,
+            morale: 0.5,
+            has_ball: false,
+            persona: Persona::default(),
+        }
+    }
+
+    #[test]
+    fn nearest_team_controls_the_space() {
+        let pitch = Pitch::standard();
+        let players = [player(1, 0, 19.0, 30.0), player(2, 1, 49.0, 30.0)];
+        let control = PitchControl::compute(&pitch, &players);
+        assert_eq!(control.cells().count(), control.columns * control.rows);
+        assert!(control.control_at(&Vec2::new(19.0, 30.0), 0) > 0.9);
+        assert!(control.control_at(&Vec2::new(49.0, 30.0), 1) > 0.9);
+        let midway = control.control_at(&Vec2::new(34.0, 30.0), 0);
+        assert!((midway - 0.5).abs() < 0.05, "midway control: {}", midway);
+
+        // 달려가는 방향의 공간을 더 많이 통제
+        let mut running = players.clone();
+        running[0].velocity = Vec2::new(6.0, 0.0);
+        let moving = PitchControl::compute(&pitch, &running);
+        assert!(moving.control_at(&Vec2::new(34.0, 30.0), 0) > midway);
+    }
+
+    #[test]
+    fn pass_success_drops_through_contested_lanes_and_to_marked_receivers() {
+        let pitch = Pitch::standard();
+        let passer = player(1, 0, 34.0, 30.0);
+        let receiver = player(2, 0, 34.0, 50.0);
+        let open = PitchControl::compute(&pitch, &[passer.clone(), receiver.clone(), player(3, 1, 60.0, 80.0)]);
+        let blocked = PitchControl::compute(&pitch, &[passer.clone(), receiver.clone(), player(3, 1, 34.0, 40.0)]);
+        let marked = PitchControl::compute(&pitch, &[passer.clone(), receiver.clone(), player(3, 1, 35.5, 51.0)]);
+
+        let open_pass = open.pass_success_probability(&passer.position, &receiver.position, 0);
+        assert!(open_pass > 0.9, "open pass: {}", open_pass);
+        assert!(blocked.pass_success_probability(&passer.position, &receiver.position, 0) < open_pass);
+        assert!(marked.pass_success_probability(&passer.position, &receiver.position, 0) < 0.8);
+    }
+
+    #[test]
+    fn open_zones_are_reported_from_the_team_view() {
+        let pitch = Pitch::standard();
+        // 홈 팀(아래쪽 골 수비)만 상대 진영 왼쪽 측면에 있음
+        let players = [player(1, 0, 8.0, 90.0), player(2, 1, 60.0, 10.0)];
+        let control = PitchControl::compute(&pitch, &players);
+        let home = control.open_zones(&pitch, 0, true);
+        assert_eq!((home[0].third, home[0].channel), (Third::Attacking, Channel::Left));
+        assert!(home[0].center.x < pitch.width / 3.0 && home[0].center.y > pitch.length * 2.0 / 3.0);
+        assert!(home.iter().all(|zone| zone.third != Third::Defensive));
+        assert_eq!(format!("{} {}", home[0].third, home[0].channel), "attacking left");
+        assert_eq!(serde_json::to_value(home[0].third).unwrap(), "attacking");
+
+        // 같은 공간이 어웨이 팀에게는 자기 진영 오른쪽
+        let away = control.zone_control(&pitch, 1, false);
+        let zone = away.iter().find(|zone| zone.third == Third::Defensive && zone.channel == Channel::Right).unwrap();
+        assert!(zone.control < 0.5);
+    }
+}
@@ -0,0 +1,144 @@
+use serde::{Deserialize, Serialize};
+use crate::types::Player;
+
+/// 기본 키커 수 (5명씩 찬 뒤 동점이면 서든데스)
+pub const SHOOTOUT_ROUNDS: usize = 5;
+
+/// 승부차기 상태
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ShootoutState {
+    /// 팀별 키커 순서 (선수 ID)
+    pub takers: [Vec<u32>; 2],
+    /// 팀별 킥 결과 (true = 성공)
+    pub kicks: [Vec<bool>; 2],
+    /// 다음에 찰 팀
+    pub next_team: u8,
+    /// 다음 킥 시점 (ms)
+    pub next_kick_at_ms: u64,
+}
+
+impl ShootoutState {
+    /// 홈 팀이 먼저 차는 승부차기 시작
+    pub fn new(home_takers: Vec<u32>, away_takers: Vec<u32>, start_ms: u64) -> Self {
+        Self {
+            takers: [home_takers, away_takers],
+            kicks: [Vec::new(), Vec::new()],
+            next_team: 0,
+            next_kick_at_ms: start_ms,
+        }
+    }
+
+    /// 팀별 성공 수
+    pub fn score(&self, team_id: u8) -> usize {
+        self.kicks[team_id as usize].iter().filter(|scored| **scored).count()
+    }
+
+    /// 다음 키커 (모든 선수가 찬 뒤에는 처음부터 다시)
+    pub fn next_taker(&self) -> Option<u32> {
+        let team = self.next_team as usize;
+        let order = &self.takers[team];
+        if order.is_empty() {
+            return None;
+        }
+        Some(order[self.kicks[team].len() % order.len()])
+    }
+
+    /// 킥 결과 기록 후 다음 팀으로 전환
+    pub fn record_kick(&mut self, scored: bool) {
+        self.kicks[self.next_team as usize].push(scored);
+        self.next_team = 1 - self.next_team;
+    }
+
+    /// 승부가 결정되었으면 승리 팀 반환
+    ///
+    /// 5번씩 차는 동안에는 남은 킥을 모두 넣어도 따라잡을 수 없을 때 종료,
+    /// 이후에는 같은 수를 찬 뒤 점수가 다르면 종료 (서든데스)
+    pub fn winner(&self) -> Option<u8> {
+        let taken = [self.kicks[0].len(), self.kicks[1].len()];
+        let scores = [self.score(0), self.score(1)];
+
+        if taken[0] <= SHOOTOUT_ROUNDS && taken[1] <= SHOOTOUT_ROUNDS {
+            let remaining = [SHOOTOUT_ROUNDS - taken[0], SHOOTOUT_ROUNDS - taken[1]];
+            if scores[0] > scores[1] + remaining[1] {
+                return Some(0);
+            }
+            if scores[1] > scores[0] + remaining[0] {
+                return Some(1);
+            }
+            if taken[0] < SHOOTOUT_ROUNDS || taken[1] < SHOOTOUT_ROUNDS {
+                return None;
+            }
+        }
+
+        if taken[0] == taken[1] && scores[0] != scores[1] {
+            Some(if scores[0] > scores[1] { 0 } else { 1 })
+        } else {
+            None
+        }
+    }
+}
+
+/// 키커 순서: 자신감과 인내심이 높은 선수부터, 골키퍼는 마지막
+pub fn order_takers(players: &[&Player]) -> Vec<u32> {
+    let mut ranked: Vec<(u32, bool, f32)> = players.iter()
+        .map(|p| (p.id, p.is_goalkeeper(), p.persona.confidence * 0.6 + p.persona.patience * 0.4))
+        .collect();
+    ranked.sort_by(|a, b| a.1.cmp(&b.1).then(b.2.total_cmp(&a.2)).then(a.0.cmp(&b.0)));
+    ranked.into_iter().map(|(id, _, _)| id).collect()
+}
+
+/// 킥이 골문 안으로 향할 확률 (침착할수록 높음)
+pub fn on_target_probability(taker: &Player) -> f32 {
+    (0.88 + (taker.persona.patience - 0.5) * 0.12).clamp(0.7, 0.98)
+}
+
+/// 골문으로 향한 킥을 골키퍼가 막을 확률
+pub fn save_probability(taker: &Player, keeper: Option<&Player>) -> f32 {
+    let keeper_factor = keeper.map(|k| (k.persona.confidence - 0.5) * 0.15).unwrap_or(-0.1);
+    (0.22 + keeper_factor - (taker.persona.confidence - 0.5) * 0.15).clamp(0.05, 0.5)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn state_with(home: &[bool], away: &[bool]) -> ShootoutState {
+        let mut state = ShootoutState::new(vec![0, 1, 2, 3, 4], vec![5, 6, 7, 8, 9], 0);
+        state.kicks = [home.to_vec(), away.to_vec()];
+        state
+    }
+
+    #[test]
+    fn best_of_five_ends_early_when_unreachable() {
+        assert_eq!(state_with(&[true, true, true], &[false, false]).winner(), None);
+        assert_eq!(state_with(&[true, true, true], &[false, false, false]).winner(), Some(0));
+        assert_eq!(state_with(&[false, false, false, false], &[true, true, true]).winner(), Some(1));
+    }
+
+    #[test]
+    fn level_after_five_goes_to_sudden_death() {
+        let five = [true, false, true, true, false];
+        assert_eq!(state_with(&five, &five).winner(), None);
+
+        let mut home = five.to_vec();
+        home.push(true);
+        assert_eq!(state_with(&home, &five).winner(), None);
+
+        let mut away = five.to_vec();
+        away.push(false);
+        assert_eq!(state_with(&home, &away).winner(), Some(0));
+    }
+
+    #[test]
+    fn takers_cycle_after_everyone_has_kicked() {
+        let mut state = ShootoutState::new(vec![3, 1], vec![7], 0);
+        assert_eq!(state.next_taker(), Some(3));
+        state.record_kick(true);
+        assert_eq!(state.next_taker(), Some(7));
+        state.record_kick(true);
+        assert_eq!(state.next_taker(), Some(1));
+        state.record_kick(true);
+        state.record_kick(true);
+        assert_eq!(state.next_taker(), Some(3));
+    }
+}
@@ -0,0 +1,71 @@
+/// 이 비율 이상의 최고 속도로 달리면 스프린트로 간주
+pub const SPRINT_THRESHOLD: f32 = 0.7;
+/// 이 속도 이하로 움직이면 걷기로 간주 (m/s)
+pub const WALK_SPEED: f32 = 1.5;
+/// 하프타임 휴식으로 회복되는 체력
+pub const HALF_TIME_RECOVERY: f32 = 0.25;
+/// 연장전 시작 전 짧은 휴식으로 회복되는 체력
+pub const EXTRA_TIME_RECOVERY: f32 = 0.1;
+/// 지친 선수로 표시하는 체력 기준
+pub const TIRED_THRESHOLD: f32 = 0.5;
+
+/// 조깅 1m당 체력 소모
+const DRAIN_PER_METER: f32 = 0.00012;
+/// 스프린트 시 소모 배율
+const SPRINT_DRAIN_MULTIPLIER: f32 = 2.5;
+/// 걷는 동안 초당 회복량
+const WALK_RECOVERY_PER_SECOND: f32 = 0.002;
+/// 데드볼 동안 초당 회복량
+const DEAD_BALL_RECOVERY_PER_SECOND: f32 = 0.004;
+
+/// 체력에 따른 능력치 배율 (0.6 ~ 1.0)
+///
+/// 최고 속도, 가속, 태클/슈팅/패스 정확도에 곱해짐
+pub fn performance_factor(stamina: f32) -> f32 {
+    0.6 + 0.4 * stamina.clamp(0.0, 1.0)
+}
+
+/// 한 스텝 동안의 체력 변화 적용
+///
+/// 이동 거리와 강도(최고 속도 대비 비율)에 비례해 소모되고, 작업량이 높을수록
+/// 더 강하게 뛰어 소모가 큼. 걷거나 데드볼일 때는 회복
+pub fn update_stamina(
+    stamina: f32,
+    distance: f32,
+    top_speed: f32,
+    work_rate: f32,
+    delta_time: f32,
+    dead_ball: bool,
+) -> f32 {
+    let speed = if delta_time > 0.0 { distance / delta_time } else { 0.0 };
+    let next = if dead_ball {
+        stamina + DEAD_BALL_RECOVERY_PER_SECOND * delta_time
+    } else if speed <= WALK_SPEED {
+        stamina + WALK_RECOVERY_PER_SECOND * delta_time
+    } else {
+        let sprinting = top_speed > 0.0 && speed >= top_speed * SPRINT_THRESHOLD;
+        let intensity = if sprinting { SPRINT_DRAIN_MULTIPLIER } else { 1.0 };
+        stamina - distance * DRAIN_PER_METER * intensity * (0.75 + 0.5 * work_rate)
+    };
+    next.clamp(0.0, 1.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sprinting_drains_faster_than_jogging() {
+        let jog = update_stamina(1.0, 0.25, 5.0, 0.5, 0.1, false);
+        let sprint = update_stamina(1.0, 0.45, 5.0, 0.5, 0.1, false);
+        assert!(jog < 1.0);
+        assert!(1.0 - sprint > (1.0 - jog) * 2.0);
+    }
+
+    #[test]
+    fn walking_and_dead_balls_recover() {
+        assert!(update_stamina(0.5, 0.1, 5.0, 0.5, 0.1, false) > 0.5);
+        assert!(update_stamina(0.5, 0.4, 5.0, 0.5, 0.1, true) > 0.5);
+        assert_eq!(update_stamina(1.0, 0.0, 5.0, 0.5, 0.1, true), 1.0);
+    }
+}
@@ -0,0 +1,282 @@
+use serde::{Deserialize, Serialize};
+use crate::events::{EventOutcome, EventPayload, EventType, MatchEvent};
+
+/// 의도 목표 지점에 이 거리 안으로 들어오면 의도를 완수한 것으로 봄 (m)
+pub const INTENT_REACHED_DISTANCE: f32 = 2.0;
+/// 이벤트에 기록되는 승부차기 피리어드 이름 (`Period::Penalties`의 Debug 표기)
+const SHOOTOUT_PERIOD: &str = "Penalties";
+
+/// 선수 또는 팀의 누적 기록
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+pub struct StatLine {
+    pub passes_attempted: u32,
+    pub passes_completed: u32,
+    pub shots: u32,
+    pub shots_on_target: u32,
+    pub xg: f32,
+    pub tackles_attempted: u32,
+    pub tackles_won: u32,
+    /// 이동 거리 (m)
+    pub distance_m: f32,
+    pub intents_issued: u32,
+    pub intents_completed: u32,
+}
+
+impl StatLine {
+    /// 패스 성공률 (시도가 없으면 None)
+    pub fn pass_completion(&self) -> Option<f32> {
+        (self.passes_attempted > 0).then(|| self.passes_completed as f32 / self.passes_attempted as f32)
+    }
+
+    /// 의도 완수율 (받은 의도가 없으면 None)
+    pub fn intent_success_rate(&self) -> Option<f32> {
+        (self.intents_issued > 0).then(|| self.intents_completed as f32 / self.intents_issued as f32)
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PlayerStats {
+    pub player_id: u32,
+    pub team_id: u8,
+    #[serde(flatten)]
+    pub line: StatLine,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TeamStats {
+    pub team_id: u8,
+    /// 인플레이 중 마지막으로 공을 만진 팀으로 센 점유 시간 (ms)
+    pub possession_ms: u64,
+    #[serde(flatten)]
+    pub line: StatLine,
+}
+
+/// 이번 스텝에 진행 중인 의도 (통계 집계용)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct IntentProgress {
+    pub player_id: u32,
+    pub team_id: u8,
+    pub created_at_ms: u64,
+    /// 목표 지점에 도달했는지
+    pub reached: bool,
+}
+
+/// 지난 스텝까지 진행 중이던 의도
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+struct ActiveIntent {
+    player_id: u32,
+    created_at_ms: u64,
+    /// 이미 완수로 셌는지
+    completed: bool,
+}
+
+/// 이벤트 스트림과 매 스텝 상태를 누적한 경기 통계
+///
+/// 승부차기 이벤트는 경기 기록에 포함하지 않음. 집계 위치와 진행 중인 의도도 함께 직렬화해
+/// 역직렬화한 통계에 같은 경기의 이벤트를 이어서 집계해도 중복으로 세지 않음
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MatchStats {
+    pub teams: [TeamStats; 2],
+    /// 선수별 기록 (선수 ID 순, 교체 선수 포함)
+    pub players: Vec<PlayerStats>,
+    /// 다음에 집계할 이벤트 위치
+    #[serde(default)]
+    event_cursor: usize,
+    #[serde(default)]
+    active_intents: Vec<ActiveIntent>,
+}
+
+impl Default for MatchStats {
+    fn default() -> Self {
+        Self {
+            teams: [0, 1].map(|team_id| TeamStats { team_id, ..TeamStats::default() }),
+            players: Vec::new(),
+            event_cursor: 0,
+            active_intents: Vec::new(),
+        }
+    }
+}
+
+impl MatchStats {
+    /// 선수 기록과 소속 팀 기록을 함께 갱신
+    fn update(&mut self, player_id: u32, team_id: u8, apply: impl Fn(&mut StatLine)) {
+        let index = match self.players.binary_search_by_key(&player_id, |p| p.player_id) {
+            Ok(index) => index,
+            Err(index) => {
+                self.players.insert(index, PlayerStats { player_id, team_id, ..PlayerStats::default() });
+                index
+            }
+        };
+        apply(&mut self.players[index].line);
+        if let Some(team) = self.teams.get_mut(team_id as usize) {
+            apply(&mut team.line);
+        }
+    }
+
+    pub fn player(&self, player_id: u32) -> Option<&PlayerStats> {
+        self.players.iter().find(|p| p.player_id == player_id)
+    }
+
+    /// 팀의 점유율 (0.0 ~ 1.0, 점유 기록이 없으면 0.5)
+    pub fn possession_share(&self, team_id: u8) -> f32 {
+        let total = self.teams[0].possession_ms + self.teams[1].possession_ms;
+        if total == 0 {
+            return 0.5;
+        }
+        self.teams[team_id as usize].possession_ms as f32 / total as f32
+    }
+
+    /// 아직 집계하지 않은 이벤트를 반영
+    pub fn record_events(&mut self, events: &[MatchEvent]) {
+        for event in &events[self.event_cursor.min(events.len())..] {
+            if event.period == SHOOTOUT_PERIOD {
+                continue;
+            }
+            let (Ok(player_id), Ok(team_id)) = (event.player_id.parse::<u32>(), event.team_id.parse::<u8>()) else {
+                continue;
+            };
+            match (&event.event_type, &event.payload) {
+                (EventType::Pass, _) => {
+                    let completed = matches!(event.outcome, EventOutcome::Complete);
+                    self.update(player_id, team_id, |line| {
+                        line.passes_attempted += 1;
+                        line.passes_completed += completed as u32;
+                    });
+                }
+                (EventType::Shot, EventPayload::Shot { on_target, xg, .. }) => {
+                    self.update(player_id, team_id, |line| {
+                        line.shots += 1;
+                        line.shots_on_target += *on_target as u32;
+                        line.xg += xg;
+                    });
+                }
+                (EventType::Tackle, _) => {
+                    let won = matches!(event.outcome, EventOutcome::Success);
+                    self.update(player_id, team_id, |line| {
+                        line.tackles_attempted += 1;
+                        line.tackles_won += won as u32;
+                    });
+                }
+                _ => {}
+            }
+        }
+        self.event_cursor = events.len();
+    }
+
+    /// 인플레이 중 점유 시간 추가
+    pub fn record_possession(&mut self, team_id: u8, ms: u64) {
+        if let Some(team) = self.teams.get_mut(team_id as usize) {
+            team.possession_ms += ms;
+        }
+    }
+
+    pub fn record_distance(&mut self, player_id: u32, team_id: u8, distance: f32) {
+        self.update(player_id, team_id, |line| line.distance_m += distance);
+    }
+
+    /// 진행 중인 의도 반영: 처음 보는 의도는 지시, 처음 목표에 도달한 의도는 완수로 셈
+    pub fn record_intents(&mut self, intents: &[IntentProgress]) {
+        let mut active = Vec::with_capacity(intents.len());
+        for intent in intents {
+            let previous = self.active_intents.iter()
+                .find(|active| active.player_id == intent.player_id && active.created_at_ms == intent.created_at_ms)
+                .map(|active| active.completed);
+            if previous.is_none() {
+                self.update(intent.player_id, intent.team_id, |line| line.intents_issued += 1);
+            }
+            let completed = previous.unwrap_or(false);
+            if intent.reached && !completed {
+                self.update(intent.player_id, intent.team_id, |line| line.intents_completed += 1);
+            }
+            active.push(ActiveIntent {
+                player_id: intent.player_id,
+                created_at_ms: intent.created_at_ms,
+                completed: completed || intent.reached,
+            });
+        }
+        self.active_intents = active;
+    }
+
+    pub fn to_json(&self) -> serde_json::Result<String> {
+        serde_json::to_string_pretty(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{Period, Vec2};
+
+    fn event(event_type: EventType, period: Period, player_id: u32, team_id: u8, payload: EventPayload, outcome: EventOutcome) -> MatchEvent {
+        MatchEvent {
+            id: String::new(),
+            t_ms: 0,
+            period: format!("{:?}", period),
+            event_type,
+            team_id: team_id.to_string(),
+            player_id: player_id.to_string(),
+            location: Vec2::default(),
+            payload,
+            outcome,
+        }
+    }
+
+    #[test]
+    fn events_fold_into_player_and_team_lines_once() {
+        let pass = || EventPayload::Pass { target_player_id: "2".to_string(), distance: 10.0, risk: 0.0 };
+        let shot = |xg| EventPayload::Shot { distance: 11.0, angle: 0.0, on_target: true, xg };
+        let mut events = vec![
+            event(EventType::Pass, Period::H1, 1, 0, pass(), EventOutcome::Complete),
+            event(EventType::Pass, Period::H1, 1, 0, pass(), EventOutcome::Incomplete),
+            event(EventType::Shot, Period::H1, 2, 0, shot(0.3), EventOutcome::Success),
+            event(EventType::Tackle, Period::H2, 7, 1, EventPayload::Empty, EventOutcome::Failure),
+        ];
+        let mut stats = MatchStats::default();
+        stats.record_events(&events);
+        stats.record_events(&events);
+
+        let passer = stats.player(1).unwrap();
+        assert_eq!((passer.line.passes_attempted, passer.line.passes_completed), (2, 1));
+        assert_eq!(stats.teams[0].line.pass_completion(), Some(0.5));
+        assert_eq!(stats.teams[0].line.shots_on_target, 1);
+        assert!((stats.teams[0].line.xg - 0.3).abs() < 1e-6);
+        assert_eq!((stats.teams[1].line.tackles_attempted, stats.teams[1].line.tackles_won), (1, 0));
+
+        // 승부차기 킥은 경기 기록에서 제외
+        assert_eq!(format!("{:?}", Period::Penalties), SHOOTOUT_PERIOD);
+        events.push(event(EventType::Shot, Period::Penalties, 2, 0, shot(0.76), EventOutcome::Success));
+        stats.record_events(&events);
+        assert_eq!(stats.player(2).unwrap().line.shots, 1);
+
+        let json: serde_json::Value = serde_json::from_str(&stats.to_json().unwrap()).unwrap();
+        assert_eq!(json["teams"][0]["passes_attempted"], 2);
+        assert_eq!(json["players"][0]["player_id"], 1);
+
+        // 역직렬화한 통계에 이어서 집계해도 이미 센 이벤트는 다시 세지 않음
+        let mut restored: MatchStats = serde_json::from_str(&stats.to_json().unwrap()).unwrap();
+        events.push(event(EventType::Pass, Period::H2, 1, 0, pass(), EventOutcome::Complete));
+        restored.record_events(&events);
+        assert_eq!(restored.player(1).unwrap().line.passes_attempted, 3);
+        assert_eq!(restored.player(2).unwrap().line.shots, 1);
+    }
+
+    #[test]
+    fn intents_count_once_when_issued_and_once_when_reached() {
+        let mut stats = MatchStats::default();
+        let progress = |reached| IntentProgress { player_id: 3, team_id: 0, created_at_ms: 1000, reached };
+        stats.record_intents(&[progress(false)]);
+        stats.record_intents(&[progress(true)]);
+        stats.record_intents(&[progress(true)]);
+        let line = stats.player(3).unwrap().line;
+        assert_eq!((line.intents_issued, line.intents_completed), (1, 1));
+
+        // 새 의도로 교체되면 다시 셈
+        stats.record_intents(&[IntentProgress { created_at_ms: 5000, ..progress(false) }]);
+        assert_eq!(stats.teams[0].line.intents_issued, 2);
+        assert_eq!(stats.teams[0].line.intent_success_rate(), Some(0.5));
+
+        stats.record_possession(0, 300);
+        stats.record_possession(1, 100);
+        assert_eq!(stats.possession_share(0), 0.75);
+    }
+}
@@ -0,0 +1,41 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// 감독이 확정한 교체 (다음 데드볼에 실행)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Substitution {
+    pub team_id: u8,
+    /// 나가는 선수
+    pub player_out: u32,
+    /// 벤치에서 들어오는 선수
+    pub player_in: u32,
+}
+
+/// 교체 요청 거절 사유
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SubstitutionError {
+    /// 경기가 끝났거나 승부차기 중
+    MatchNotInPlay,
+    /// 해당 팀 선수로 경기장에 없음
+    PlayerNotOnPitch(u32),
+    /// 해당 팀 벤치에 없음
+    PlayerNotOnBench(u32),
+    /// 이미 교체 대기 중인 선수
+    AlreadyRequested(u32),
+    /// 경기당 교체 한도 도달 (대기 중인 교체 포함)
+    LimitReached,
+}
+
+impl fmt::Display for SubstitutionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SubstitutionError::MatchNotInPlay => write!(f, "match is not in play"),
+            SubstitutionError::PlayerNotOnPitch(id) => write!(f, "player {} is not on the pitch", id),
+            SubstitutionError::PlayerNotOnBench(id) => write!(f, "player {} is not on the bench", id),
+            SubstitutionError::AlreadyRequested(id) => write!(f, "player {} is already in a pending substitution", id),
+            SubstitutionError::LimitReached => write!(f, "substitution limit reached"),
+        }
+    }
+}
+
+impl std::error::Error for SubstitutionError {}
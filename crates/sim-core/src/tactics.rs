@@ -0,0 +1,224 @@
+use crate::config::Pitch;
+use crate::types::{Behavior, Formation, Role, Tactics, Trigger, Vec2, Zone};
+
+/// 줄별 기본 깊이 (자기 골라인 0.0 ~ 상대 골라인 1.0)
+const DEFENSE_DEPTH: f32 = 0.25;
+const MIDFIELD_DEPTH: f32 = 0.42;
+const ATTACK_DEPTH: f32 = 0.65;
+/// 공을 가졌을 때 라인 전체가 올라가는 정도
+const IN_POSSESSION_SHIFT: f32 = 0.15;
+/// 공을 잃었을 때 라인 전체가 내려가는 정도
+const OUT_OF_POSSESSION_SHIFT: f32 = -0.1;
+/// 공 위치를 따라 블록이 움직이는 비율 (세로, 가로)
+const BALL_FOLLOW_DEPTH: f32 = 0.35;
+const BALL_FOLLOW_WIDTH: f32 = 0.3;
+/// 오버랩 역할이 공격 시 추가로 올라가는 정도
+const OVERLAP_PUSH: f32 = 0.12;
+/// 압박 역할이 수비 시 추가로 올라가는 정도
+const PRESS_PUSH: f32 = 0.06;
+
+/// 팀 관점의 경기 국면
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PlayPhase {
+    /// 우리 팀이 공을 가짐
+    InPossession,
+    /// 상대 팀이 공을 가짐
+    OutOfPossession,
+    /// 루즈볼 또는 데드볼
+    Contested,
+}
+
+impl Formation {
+    /// 골키퍼를 제외한 (수비, 미드필더, 공격) 인원
+    pub fn lines(&self) -> [usize; 3] {
+        match self {
+            Formation::TwoOneOne => [2, 1, 1],
+            Formation::ThreeOneZero => [3, 1, 0],
+            Formation::TwoTwoZero => [2, 2, 0],
+            Formation::TwoThreeOne => [2, 3, 1],
+            Formation::ThreeTwoOne => [3, 2, 1],
+            Formation::FourFourTwo => [4, 4, 2],
+            Formation::FourThreeThree => [4, 3, 3],
+        }
+    }
+
+    /// 필드 플레이어 수
+    pub fn outfield_count(&self) -> usize {
+        self.lines().iter().sum()
+    }
+
+    /// 팀 인원에 맞는 기본 포메이션
+    pub fn default_for(team_size: usize) -> Self {
+        match team_size {
+            0..=5 => Formation::TwoOneOne,
+            6..=8 => Formation::TwoThreeOne,
+            _ => Formation::FourFourTwo,
+        }
+    }
+
+    /// 필드 플레이어 자리별 (영역, 정규화 좌표)
+    ///
+    /// 좌표는 팀 관점: x는 왼쪽 터치라인 0.0 ~ 오른쪽 1.0, y는 자기 골라인 0.0 ~ 상대 골라인 1.0.
+    /// 수비 → 미드필더 → 공격, 각 줄 안에서는 왼쪽부터 순서대로
+    pub fn slots(&self) -> Vec<(Zone, Vec2)> {
+        let zones = [
+            (Zone::Defense, DEFENSE_DEPTH),
+            (Zone::Midfield, MIDFIELD_DEPTH),
+            (Zone::Attack, ATTACK_DEPTH),
+        ];
+        zones.iter()
+            .zip(self.lines())
+            .flat_map(|(&(zone, depth), count)| {
+                (0..count).map(move |slot| {
+                    let x = 0.1 + 0.8 * (slot as f32 + 0.5) / count as f32;
+                    (zone, Vec2::new(x, depth))
+                })
+            })
+            .collect()
+    }
+}
+
+impl Tactics {
+    /// 포메이션의 기본 역할로 전술 생성
+    ///
+    /// 수비 4명 라인의 측면 수비수는 오버랩, 최전방은 압박, 나머지는 위치 유지.
+    /// 오버랩 역할이 있으면 양쪽 오버랩 트리거를 켬. 역압박은 반칙이 늘어나므로 직접 켜야 함
+    pub fn new(formation: Formation) -> Self {
+        let [defenders, _, _] = formation.lines();
+        let roles: Vec<Role> = formation.slots()
+            .into_iter()
+            .enumerate()
+            .map(|(slot, (zone, _))| {
+                let wide_defender = zone == Zone::Defense && defenders >= 4
+                    && (slot == 0 || slot == defenders - 1);
+                let behavior = match zone {
+                    Zone::Defense if wide_defender => Behavior::Overlap,
+                    Zone::Attack => Behavior::Press,
+                    _ => Behavior::Hold,
+                };
+                Role { zone, behavior }
+            })
+            .collect();
+        let triggers = if roles.iter().any(|role| role.behavior == Behavior::Overlap) {
+            vec![Trigger::OverlapLeft, Trigger::OverlapRight]
+        } else {
+            Vec::new()
+        };
+        Self {
+            formation,
+            roles,
+            triggers,
+        }
+    }
+
+    /// 팀 인원에 맞는 기본 전술
+    pub fn default_for(team_size: usize) -> Self {
+        Self::new(Formation::default_for(team_size))
+    }
+
+    /// 자리 번호의 역할 (필드 플레이어가 자리보다 많으면 순환)
+    pub fn role(&self, slot: usize) -> Option<Role> {
+        if self.roles.is_empty() {
+            return None;
+        }
+        self.roles.get(slot % self.roles.len()).copied()
+    }
+}
+
+/// 포메이션 자리의 현재 기본 위치
+///
+/// 기본 자리에서 국면에 따라 라인 전체가 오르내리고, 공 위치를 따라 블록이 이동함.
+/// 오버랩 역할은 공격 시 측면으로 더 올라가고, 압박 역할은 수비 시 더 높게 섬
+pub fn home_position(
+    pitch: &Pitch,
+    tactics: &Tactics,
+    slot: usize,
+    defends_low_end: bool,
+    ball_position: &Vec2,
+    phase: PlayPhase,
+) -> Vec2 {
+    let slots = tactics.formation.slots();
+    if slots.is_empty() {
+        return pitch.center();
+    }
+    let (_, base) = slots[slot % slots.len()];
+    let behavior = tactics.role(slot).map(|role| role.behavior);
+
+    // 공 위치를 팀 관점 정규화 좌표로 변환
+    let to_team = |x: f32, y: f32| {
+        if defends_low_end {
+            Vec2::new(x / pitch.width, y / pitch.length)
+        } else {
+            Vec2::new(1.0 - x / pitch.width, 1.0 - y / pitch.length)
+        }
+    };
+    let ball = to_team(ball_position.x, ball_position.y);
+
+    let mut depth = base.y + BALL_FOLLOW_DEPTH * (ball.y - 0.5);
+    let mut width = base.x + BALL_FOLLOW_WIDTH * (ball.x - 0.5);
+    match phase {
+        PlayPhase::InPossession => {
+            depth += IN_POSSESSION_SHIFT;
+            if behavior == Some(Behavior::Overlap) {
+                depth += OVERLAP_PUSH;
+                width += if base.x < 0.5 { -0.1 } else { 0.1 };
+            }
+        }
+        PlayPhase::OutOfPossession => {
+            depth += OUT_OF_POSSESSION_SHIFT;
+            if behavior == Some(Behavior::Press) {
+                depth += PRESS_PUSH;
+            }
+        }
+        PlayPhase::Contested => {}
+    }
+    let depth = depth.clamp(0.05, 0.92);
+    let width = width.clamp(0.05, 0.95);
+
+    if defends_low_end {
+        Vec2::new(width * pitch.width, depth * pitch.length)
+    } else {
+        Vec2::new((1.0 - width) * pitch.width, (1.0 - depth) * pitch.length)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn formations_fill_their_team_size() {
+        assert_eq!(Formation::default_for(5).outfield_count(), 4);
+        assert_eq!(Formation::default_for(7).outfield_count(), 6);
+        assert_eq!(Formation::default_for(11).outfield_count(), 10);
+        let tactics = Tactics::new(Formation::FourFourTwo);
+        assert_eq!(tactics.roles.len(), 10);
+        assert_eq!(tactics.role(0).unwrap().behavior, Behavior::Overlap);
+        assert_eq!(tactics.role(9).unwrap().zone, Zone::Attack);
+        assert!(tactics.triggers.contains(&Trigger::OverlapLeft));
+        assert!(!tactics.triggers.contains(&Trigger::PressOnLoss));
+        assert!(Tactics::new(Formation::TwoOneOne).triggers.is_empty());
+    }
+
+    #[test]
+    fn home_positions_shift_with_ball_and_phase() {
+        let pitch = Pitch::standard();
+        let tactics = Tactics::new(Formation::FourFourTwo);
+        let center = pitch.center();
+        let striker = 8;
+
+        let attacking = home_position(&pitch, &tactics, striker, true, &center, PlayPhase::InPossession);
+        let defending = home_position(&pitch, &tactics, striker, true, &center, PlayPhase::OutOfPossession);
+        assert!(attacking.y > defending.y);
+
+        // 공이 상대 진영 깊숙이 가면 블록 전체가 따라 올라감
+        let deep_ball = Vec2::new(center.x, pitch.length * 0.9);
+        let pushed = home_position(&pitch, &tactics, striker, true, &deep_ball, PlayPhase::InPossession);
+        assert!(pushed.y > attacking.y);
+
+        // 반대쪽 진영 팀은 점대칭 위치
+        let mirrored = home_position(&pitch, &tactics, striker, false, &center, PlayPhase::InPossession);
+        assert!((mirrored.y - (pitch.length - attacking.y)).abs() < 1e-3);
+        assert!((mirrored.x - (pitch.width - attacking.x)).abs() < 1e-3);
+    }
+}
@@ -0,0 +1,96 @@
+use serde::{Deserialize, Serialize};
+use crate::config::Pitch;
+use crate::types::{Trigger, Vec2};
+
+/// 역압박 의도 지속 시간 (ms)
+pub const PRESS_ON_LOSS_MS: u64 = 3000;
+/// 공을 잃은 지점에서 역압박에 나서는 최대 인원
+pub const PRESS_ON_LOSS_PLAYERS: usize = 2;
+/// 역압박에 나설 수 있는 공을 잃은 지점과의 거리 (m)
+pub const PRESS_ON_LOSS_RANGE: f32 = 15.0;
+/// 오버랩 침투 의도 지속 시간 (ms)
+pub const OVERLAP_MS: u64 = 4000;
+/// 같은 팀의 같은 트리거가 다시 발동할 수 있기까지의 시간 (ms)
+pub const TRIGGER_COOLDOWN_MS: u64 = 8000;
+/// 측면으로 보는 터치라인 쪽 너비 비율
+const FLANK_WIDTH: f32 = 0.33;
+/// 오버랩이 시작되는 최소 깊이 (팀 관점, 하프라인 = 0.5)
+const OVERLAP_MIN_DEPTH: f32 = 0.5;
+/// 오버랩 선수가 공보다 앞서 달려가는 거리 (경기장 길이 비율)
+const OVERLAP_LEAD: f32 = 0.15;
+/// 오버랩 선수가 달리는 터치라인 쪽 위치 (너비 비율)
+const OVERLAP_LANE: f32 = 0.08;
+
+/// 발동된 전술 트리거 기록 (대시보드 표시용)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TriggerFiring {
+    pub t_ms: u64,
+    pub team_id: u8,
+    pub trigger: Trigger,
+    /// 발동 원인이 된 이벤트
+    pub event_id: String,
+    /// 단기 의도를 받은 선수
+    pub player_ids: Vec<u32>,
+}
+
+/// 팀 관점 정규화 좌표 (x: 왼쪽 터치라인 0.0 ~ 1.0, y: 자기 골라인 0.0 ~ 상대 골라인 1.0)
+pub(crate) fn to_team_view(pitch: &Pitch, position: &Vec2, defends_low_end: bool) -> Vec2 {
+    let x = position.x / pitch.width;
+    let y = position.y / pitch.length;
+    if defends_low_end {
+        Vec2::new(x, y)
+    } else {
+        Vec2::new(1.0 - x, 1.0 - y)
+    }
+}
+
+fn from_team_view(pitch: &Pitch, view: Vec2, defends_low_end: bool) -> Vec2 {
+    let view = if defends_low_end { view } else { Vec2::new(1.0 - view.x, 1.0 - view.y) };
+    Vec2::new(view.x * pitch.width, view.y * pitch.length)
+}
+
+/// 공을 받은 위치에 해당하는 오버랩 트리거
+///
+/// 상대 진영 측면에서 공을 받았을 때만 해당
+pub fn overlap_trigger(pitch: &Pitch, location: &Vec2, defends_low_end: bool) -> Option<Trigger> {
+    let view = to_team_view(pitch, location, defends_low_end);
+    if view.y < OVERLAP_MIN_DEPTH {
+        return None;
+    }
+    if view.x < FLANK_WIDTH {
+        Some(Trigger::OverlapLeft)
+    } else if view.x > 1.0 - FLANK_WIDTH {
+        Some(Trigger::OverlapRight)
+    } else {
+        None
+    }
+}
+
+/// 오버랩 선수가 달려갈 지점 (공보다 앞선 같은 쪽 터치라인 안쪽)
+pub fn overlap_target(pitch: &Pitch, location: &Vec2, trigger: Trigger, defends_low_end: bool) -> Vec2 {
+    let view = to_team_view(pitch, location, defends_low_end);
+    let lane = if trigger == Trigger::OverlapLeft { OVERLAP_LANE } else { 1.0 - OVERLAP_LANE };
+    let depth = (view.y + OVERLAP_LEAD).min(0.92);
+    from_team_view(pitch, Vec2::new(lane, depth), defends_low_end)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn overlaps_fire_on_the_flank_in_the_opponent_half() {
+        let pitch = Pitch::standard();
+        let left_wing = Vec2::new(5.0, 70.0);
+        assert_eq!(overlap_trigger(&pitch, &left_wing, true), Some(Trigger::OverlapLeft));
+        assert_eq!(overlap_trigger(&pitch, &Vec2::new(34.0, 70.0), true), None);
+        assert_eq!(overlap_trigger(&pitch, &Vec2::new(5.0, 30.0), true), None);
+
+        // 반대쪽 진영 팀에게는 같은 지점이 자기 진영 오른쪽
+        assert_eq!(overlap_trigger(&pitch, &Vec2::new(5.0, 30.0), false), Some(Trigger::OverlapRight));
+
+        let target = overlap_target(&pitch, &left_wing, Trigger::OverlapLeft, true);
+        assert!(target.y > left_wing.y);
+        assert!(target.x < 10.0);
+    }
+}
@@ -1,4 +1,5 @@
 use serde::{Deserialize, Serialize};
+use crate::shootout::ShootoutState;
 
 /// 플레이어 정보
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -7,14 +8,28 @@ pub struct Player {
     pub team_id: u8,
     pub role: String,
     pub position: Vec2,
+    /// 현재 속도 (m/s)
+    #[serde(default)]
+    pub velocity: Vec2,
     pub stamina: f32,
     pub morale: f32,
     pub has_ball: bool,
     pub persona: Persona,
 }
 
+impl Player {
+    pub fn is_goalkeeper(&self) -> bool {
+        self.role == ROLE_GOALKEEPER
+    }
+}
+
+/// 골키퍼 역할 이름
+pub const ROLE_GOALKEEPER: &str = "Goalkeeper";
+/// 필드 플레이어 역할 이름
+pub const ROLE_OUTFIELD: &str = "Player";
+
 /// 2D 벡터
-#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
 pub struct Vec2 {
     pub x: f32,
     pub y: f32,
@@ -48,8 +63,16 @@ impl Vec2 {
 /// 공 정보
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Ball {
+    /// 지면 위치
     pub position: Vec2,
+    /// 수평 속도 (m/s)
     pub velocity: Vec2,
+    /// 지면으로부터의 높이 (m)
+    #[serde(default)]
+    pub height: f32,
+    /// 수직 속도 (m/s, 위쪽이 +)
+    #[serde(default)]
+    pub vertical_velocity: f32,
     pub owner: Option<u32>, // 플레이어 ID
 }
 
@@ -58,9 +81,49 @@ impl Ball {
         Self {
             position: Vec2::new(x, y),
             velocity: Vec2::new(0.0, 0.0),
+            height: 0.0,
+            vertical_velocity: 0.0,
             owner: None,
         }
     }
+
+    /// 공중에 떠 있는지
+    pub fn is_airborne(&self) -> bool {
+        self.height > 0.0 || self.vertical_velocity > 0.0
+    }
+}
+
+/// 골대 (골라인 위 두 골포스트 사이의 골문)
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct Goal {
+    /// 골라인 위 골문 중심
+    pub center: Vec2,
+    /// 골포스트 사이 너비 (m)
+    pub width: f32,
+}
+
+impl Goal {
+    pub fn new(center: Vec2, width: f32) -> Self {
+        Self { center, width }
+    }
+
+    pub fn left_post(&self) -> Vec2 {
+        Vec2::new(self.center.x - self.width / 2.0, self.center.y)
+    }
+
+    pub fn right_post(&self) -> Vec2 {
+        Vec2::new(self.center.x + self.width / 2.0, self.center.y)
+    }
+
+    /// x 좌표가 두 골포스트 사이인지
+    pub fn is_between_posts(&self, x: f32) -> bool {
+        (x - self.center.x).abs() <= self.width / 2.0
+    }
+
+    /// 골라인에서 경기장 안쪽을 향하는 방향 (+1 또는 -1)
+    pub fn inward(&self) -> f32 {
+        if self.center.y <= 0.0 { 1.0 } else { -1.0 }
+    }
 }
 
 /// 페르소나 파라미터
@@ -131,8 +194,8 @@ pub struct Tactics {
     pub triggers: Vec<Trigger>,
 }
 
-/// 포메이션 (5v5 예시)
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// 포메이션 (골키퍼 제외 수비-미드필더-공격 인원)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum Formation {
     /// 2-1-1 (수비 2, 미드필더 1, 공격 1)
     TwoOneOne,
@@ -140,17 +203,25 @@ pub enum Formation {
     ThreeOneZero,
     /// 2-2-0 (수비 2, 미드필더 2)
     TwoTwoZero,
+    /// 2-3-1 (7인제)
+    TwoThreeOne,
+    /// 3-2-1 (7인제)
+    ThreeTwoOne,
+    /// 4-4-2 (11인제)
+    FourFourTwo,
+    /// 4-3-3 (11인제)
+    FourThreeThree,
 }
 
 /// 역할
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Role {
     pub zone: Zone,
     pub behavior: Behavior,
 }
 
 /// 영역
-#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum Zone {
     Defense,
     Midfield,
@@ -158,7 +229,7 @@ pub enum Zone {
 }
 
 /// 행동
-#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum Behavior {
     Hold,
     Press,
@@ -166,7 +237,7 @@ pub enum Behavior {
 }
 
 /// 트리거
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum Trigger {
     PressOnLoss,
     OverlapLeft,
@@ -180,12 +251,128 @@ pub struct MatchState {
     pub time_ms: u64,
     pub home_score: u8,
     pub away_score: u8,
+    /// 경기 진행 단계 (진행 중 / 재개 대기 / 종료)
+    pub phase: MatchPhase,
+    /// 현재 피리어드 경과 시간 (ms)
+    pub period_elapsed_ms: u64,
+    /// 현재 피리어드에 선언된 추가시간 (ms, 정규 시간이 끝나면 선언)
+    pub stoppage_ms: Option<u64>,
+    /// 후반(연장) 진영 교체 여부
+    pub ends_swapped: bool,
+    /// 승부차기 (연장 후에도 동점인 컵 경기)
+    pub shootout: Option<ShootoutState>,
 }
 
-#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+impl MatchState {
+    pub fn new() -> Self {
+        Self {
+            period: Period::H1,
+            time_ms: 0,
+            home_score: 0,
+            away_score: 0,
+            phase: MatchPhase::Live,
+            period_elapsed_ms: 0,
+            stoppage_ms: None,
+            ends_swapped: false,
+            shootout: None,
+        }
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.phase == MatchPhase::Finished
+    }
+
+    /// 승리 팀 (무승부 또는 진행 중이면 None, 동점이면 승부차기 결과)
+    pub fn winner(&self) -> Option<u8> {
+        if !self.is_finished() {
+            return None;
+        }
+        if self.home_score == self.away_score {
+            return self.shootout.as_ref().and_then(|shootout| shootout.winner());
+        }
+        Some(if self.home_score > self.away_score { 0 } else { 1 })
+    }
+}
+
+impl Default for MatchState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum Period {
     H1,
     H2,
     ExtraTime,
+    /// 승부차기
+    Penalties,
+}
+
+/// 경기 진행 단계
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum MatchPhase {
+    /// 인플레이
+    Live,
+    /// 데드볼, 세트피스 재개 대기
+    Restart,
+    /// 승부차기 진행 중
+    Shootout,
+    /// 경기 종료
+    Finished,
+}
+
+/// 경기 규칙 (시간, 연장/승부차기, 오프사이드, 교체)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MatchRules {
+    /// 전/후반 길이 (ms)
+    pub half_length_ms: u64,
+    /// 무승부 시 연장전 진행 (컵 경기)
+    pub extra_time: bool,
+    /// 연장전 길이 (ms)
+    pub extra_time_length_ms: u64,
+    /// 동점으로 끝나면 승부차기 진행 (컵 경기)
+    pub penalty_shootout: bool,
+    /// 추가시간 상한 (ms)
+    pub max_stoppage_ms: u64,
+    /// 오프사이드 적용 (소규모 경기에서는 끄는 경우가 많음)
+    pub offside: bool,
+    /// 팀당 교체 가능 인원 (0이면 교체 없음)
+    #[serde(default)]
+    pub max_substitutions: u8,
+}
+
+impl Default for MatchRules {
+    fn default() -> Self {
+        Self {
+            half_length_ms: 20 * 60 * 1000,
+            extra_time: false,
+            extra_time_length_ms: 10 * 60 * 1000,
+            penalty_shootout: false,
+            max_stoppage_ms: 5 * 60 * 1000,
+            offside: true,
+            max_substitutions: 3,
+        }
+    }
+}
+
+impl MatchRules {
+    /// 연장전과 승부차기가 있는 컵 경기 규칙
+    pub fn cup() -> Self {
+        Self {
+            extra_time: true,
+            penalty_shootout: true,
+            ..Self::default()
+        }
+    }
+
+    /// 피리어드의 정규 길이 (ms)
+    pub fn period_length_ms(&self, period: Period) -> u64 {
+        match period {
+            Period::H1 | Period::H2 => self.half_length_ms,
+            Period::ExtraTime => self.extra_time_length_ms,
+            Period::Penalties => 0,
+        }
+    }
 }
 
@@ -0,0 +1,131 @@
+use serde::{Deserialize, Serialize};
+use crate::types::{Goal, Vec2};
+
+/// 페널티킥 기대 득점 (실제 경기 평균 성공률)
+pub const PENALTY_XG: f32 = 0.76;
+/// 로지스틱 모델 계수: 상수항, 골문 시야각(rad), 거리(m)
+const XG_INTERCEPT: f32 = -3.2;
+const XG_ANGLE: f32 = 4.2;
+const XG_DISTANCE: f32 = 0.045;
+/// 수비 압박 1.0일 때 로짓 감점
+const XG_PRESSURE: f32 = 1.5;
+/// 골문을 등지고 찰 때 로짓 감점
+const XG_OFF_BALANCE: f32 = 0.8;
+/// 헤더 로짓 감점
+const XG_HEADER: f32 = 1.0;
+/// 직접 프리킥 로짓 감점 (수비벽)
+const XG_FREE_KICK: f32 = 0.4;
+/// 이 속도보다 느리면 자세를 잡고 선 채로 차는 것으로 봄 (m/s)
+const SET_SPEED: f32 = 0.5;
+
+/// 슈팅 종류
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ShotType {
+    /// 필드 플레이 중 발로 찬 슈팅
+    Foot,
+    /// 헤더 (현재 엔진은 공중볼 슈팅을 만들지 않음)
+    Header,
+    DirectFreeKick,
+    Penalty,
+}
+
+/// 슈팅 순간의 상황
+#[derive(Debug, Clone, Copy)]
+pub struct ShotSituation {
+    pub position: Vec2,
+    /// 슈팅 직전 선수 속도 (몸 방향 판단용)
+    pub velocity: Vec2,
+    /// 수비 압박 (0.0 ~ 1.0)
+    pub pressure: f32,
+    pub shot_type: ShotType,
+}
+
+/// 두 골포스트가 슈팅 위치에서 이루는 각도 (rad)
+pub fn goal_mouth_angle(position: &Vec2, goal: &Goal) -> f32 {
+    let post_angle = |post: Vec2| (post.x - position.x).atan2((post.y - position.y).abs());
+    (post_angle(goal.right_post()) - post_angle(goal.left_post())).abs()
+}
+
+/// 몸 방향 (0.0: 골문 반대로 달리는 중 ~ 1.0: 골문을 향하거나 선 채로 찰 때)
+pub fn body_alignment(position: &Vec2, velocity: &Vec2, goal: &Goal) -> f32 {
+    if velocity.length() < SET_SPEED {
+        return 1.0;
+    }
+    let heading = velocity.normalize();
+    let to_goal = Vec2::new(goal.center.x - position.x, goal.center.y - position.y).normalize();
+    0.5 + 0.5 * (heading.x * to_goal.x + heading.y * to_goal.y)
+}
+
+/// 기대 득점 (0.0 ~ 1.0)
+///
+/// 거리와 골문 시야각을 로지스틱 회귀로 결합하고 압박, 몸 방향, 슈팅 종류를 로짓에 반영.
+/// 페널티킥은 상황과 관계없이 고정값
+pub fn expected_goals(shot: &ShotSituation, goal: &Goal) -> f32 {
+    if shot.shot_type == ShotType::Penalty {
+        return PENALTY_XG;
+    }
+    let distance = shot.position.distance(&goal.center);
+    let angle = goal_mouth_angle(&shot.position, goal);
+    let alignment = body_alignment(&shot.position, &shot.velocity, goal);
+    let type_penalty = match shot.shot_type {
+        ShotType::Header => XG_HEADER,
+        ShotType::DirectFreeKick => XG_FREE_KICK,
+        ShotType::Foot | ShotType::Penalty => 0.0,
+    };
+    let logit = XG_INTERCEPT + XG_ANGLE * angle - XG_DISTANCE * distance
+        - XG_PRESSURE * shot.pressure.clamp(0.0, 1.0)
+        - XG_OFF_BALANCE * (1.0 - alignment)
+        - type_penalty;
+    1.0 / (1.0 + (-logit).exp())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn goal() -> Goal {
+        Goal::new(Vec2::new(34.0, 105.0), 7.32)
+    }
+
+    fn foot_shot(x: f32, y: f32) -> ShotSituation {
+        ShotSituation { position: Vec2::new(x, y), velocity: Vec2::default(), pressure: 0.0, shot_type: ShotType::Foot }
+    }
+
+    #[test]
+    fn reference_shots() {
+        let goal = goal();
+        let penalty = ShotSituation { shot_type: ShotType::Penalty, pressure: 1.0, ..foot_shot(34.0, 94.0) };
+        assert_eq!(expected_goals(&penalty, &goal), PENALTY_XG);
+
+        // 페널티 지점에서 압박 없이 찬 필드 슈팅은 페널티킥보다 훨씬 낮음
+        let open_play = expected_goals(&foot_shot(34.0, 94.0), &goal);
+        assert!((0.2..0.35).contains(&open_play), "open play from the spot: {}", open_play);
+
+        // 골라인 가까이 측면의 좁은 각도
+        let tight_angle = expected_goals(&foot_shot(44.0, 103.0), &goal);
+        assert!(tight_angle < 0.07, "tight angle: {}", tight_angle);
+
+        // 30m 중거리
+        let long_range = expected_goals(&foot_shot(34.0, 75.0), &goal);
+        assert!(long_range < 0.04, "long range: {}", long_range);
+
+        let close_range = expected_goals(&foot_shot(34.0, 99.0), &goal);
+        assert!(close_range > open_play);
+    }
+
+    #[test]
+    fn pressure_body_position_and_shot_type_lower_xg() {
+        let goal = goal();
+        let base = foot_shot(30.0, 92.0);
+        let clean = expected_goals(&base, &goal);
+        let pressed = expected_goals(&ShotSituation { pressure: 0.6, ..base }, &goal);
+        let turning = expected_goals(&ShotSituation { velocity: Vec2::new(0.0, -4.0), ..base }, &goal);
+        let header = expected_goals(&ShotSituation { shot_type: ShotType::Header, ..base }, &goal);
+        let free_kick = expected_goals(&ShotSituation { shot_type: ShotType::DirectFreeKick, ..base }, &goal);
+        assert!(pressed < clean);
+        assert!(turning < clean);
+        assert!(header < clean);
+        assert!(free_kick < clean);
+        assert!((goal_mouth_angle(&Vec2::new(34.0, 94.0), &goal) - 2.0 * (3.66f32 / 11.0).atan()).abs() < 1e-4);
+    }
+}